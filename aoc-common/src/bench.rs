@@ -0,0 +1,176 @@
+use std::{fmt::Display, fs::read_to_string, time::Duration};
+
+use crate::{cache::read_input, AocError, Day, Part, Puzzle};
+
+/// Which input a benchmark ran against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BenchInput {
+    /// the cached puzzle input
+    Cached,
+    /// `day{day}/inputs/test_input.txt`
+    Example,
+}
+
+impl Display for BenchInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cached => write!(f, "input"),
+            Self::Example => write!(f, "example"),
+        }
+    }
+}
+
+/// The spread of one step over all runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub min: Duration,
+    pub median: Duration,
+}
+
+impl Timing {
+    fn of(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self {
+            min: samples.first().copied().unwrap_or_default(),
+            median: samples.get(samples.len() / 2).copied().unwrap_or_default(),
+        }
+    }
+}
+
+/// Parse, part 1 and part 2 timings of one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bench {
+    pub puzzle: Puzzle,
+    pub input: BenchInput,
+    pub runs: usize,
+    pub parse: Timing,
+    pub part1: Timing,
+    pub part2: Timing,
+}
+
+impl Bench {
+    /// The sum of the medians of every step
+    pub fn total(&self) -> Duration {
+        self.parse.median + self.part1.median + self.part2.median
+    }
+}
+
+/// The input to benchmark `puzzle` with, without ever going online: the
+/// cached puzzle input when there is one, otherwise the example input
+pub fn offline_input(puzzle: impl Into<Puzzle>) -> Option<(BenchInput, String)> {
+    let puzzle = puzzle.into();
+    puzzle
+        .cached_input_path()
+        .and_then(|path| read_input(&path).ok())
+        .map(|input| (BenchInput::Cached, input))
+        .or_else(|| {
+            read_to_string(puzzle.example_path())
+                .ok()
+                .filter(|input| !input.trim().is_empty())
+                .map(|input| (BenchInput::Example, input))
+        })
+}
+
+/// Solves `day` `runs` times and keeps the parse and per part timings
+pub fn bench(day: &Day, input: BenchInput, content: &str, runs: usize) -> Result<Bench, AocError> {
+    let mut parse = vec![];
+    let mut part1 = vec![];
+    let mut part2 = vec![];
+
+    for _ in 0..runs.max(1) {
+        let solved = day.solve(content.to_string(), &[Part::One, Part::Two])?;
+        parse.push(solved.parse_time);
+        for answer in solved.answers {
+            match answer.part {
+                Part::One => part1.push(answer.elapsed),
+                Part::Two => part2.push(answer.elapsed),
+            }
+        }
+    }
+
+    Ok(Bench {
+        puzzle: day.puzzle,
+        input,
+        runs: parse.len(),
+        parse: Timing::of(parse),
+        part1: Timing::of(part1),
+        part2: Timing::of(part2),
+    })
+}
+
+/// A Markdown table of the median timings, ready to paste into the README
+pub fn markdown_table(benches: &[Bench]) -> String {
+    let mut table = vec![
+        "| puzzle | input | parse | part 1 | part 2 | total |".to_string(),
+        "| --- | --- | ---: | ---: | ---: | ---: |".to_string(),
+    ];
+
+    for bench in benches {
+        table.push(format!(
+            "| {} | {} | {:.1?} | {:.1?} | {:.1?} | {:.1?} |",
+            bench.puzzle,
+            bench.input,
+            bench.parse.median,
+            bench.part1.median,
+            bench.part2.median,
+            bench.total()
+        ));
+    }
+    table.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        bench::{bench, markdown_table, Bench, BenchInput, Timing},
+        solution::Sums,
+        Day, Puzzle, Solution,
+    };
+
+    #[test]
+    fn can_time_every_step() {
+        let result = bench(&Day::of::<Sums>(), BenchInput::Example, "1\n2", 5).unwrap();
+
+        assert!(result.puzzle == Sums::PUZZLE);
+        assert!(result.runs == 5);
+        assert!(result.parse.min <= result.parse.median);
+        assert!(result.part2.min <= result.part2.median);
+    }
+
+    #[test]
+    fn can_pick_the_median() {
+        let timing = Timing::of(vec![
+            Duration::from_millis(9),
+            Duration::from_millis(1),
+            Duration::from_millis(4),
+        ]);
+
+        assert!(timing.min == Duration::from_millis(1));
+        assert!(timing.median == Duration::from_millis(4));
+    }
+
+    #[test]
+    fn can_render_markdown() {
+        let timing = |micros| Timing {
+            min: Duration::from_micros(micros),
+            median: Duration::from_micros(micros),
+        };
+        let result = Bench {
+            puzzle: Puzzle::new(2022, 11),
+            input: BenchInput::Cached,
+            runs: 10,
+            parse: timing(100),
+            part1: timing(200),
+            part2: timing(50_000),
+        };
+
+        assert!(
+            markdown_table(&[result])
+                == "| puzzle | input | parse | part 1 | part 2 | total |\n\
+                    | --- | --- | ---: | ---: | ---: | ---: |\n\
+                    | 2022 day 11 | input | 100.0µs | 200.0µs | 50.0ms | 50.3ms |"
+        );
+    }
+}
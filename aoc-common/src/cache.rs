@@ -0,0 +1,311 @@
+use std::{
+    fmt::Display,
+    fs::{create_dir_all, read, read_to_string, rename, write},
+    io,
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::{
+    client::AocClient,
+    crypt::{is_encrypted, key_path, CacheKey},
+    AocError, Puzzle,
+};
+
+/// Why a cached input can not be trusted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Corruption {
+    /// nothing (or only whitespace) was saved
+    Empty,
+    /// the site asked to log in instead of sending the input, the session
+    /// cookie was missing or expired
+    LoginPrompt,
+    /// an HTML page, like an error page, was saved instead of the input
+    Html,
+    /// the file no longer matches the checksum saved with it
+    ChecksumMismatch,
+}
+
+impl Display for Corruption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the input is empty"),
+            Self::LoginPrompt => write!(f, "a login prompt was saved instead of the input"),
+            Self::Html => write!(f, "an HTML page was saved instead of the input"),
+            Self::ChecksumMismatch => write!(f, "the input changed since it was downloaded"),
+        }
+    }
+}
+
+/// Reads a cached input, decrypting it with `key` if it was saved encrypted
+pub(crate) fn read_cached(path: &Path, key: Option<&CacheKey>) -> Result<String, AocError> {
+    let bytes = read(path).map_err(|e| AocError::io(path, e))?;
+    decode(path, bytes, key)
+}
+
+fn decode(path: &Path, bytes: Vec<u8>, key: Option<&CacheKey>) -> Result<String, AocError> {
+    if !is_encrypted(&bytes) {
+        return String::from_utf8(bytes)
+            .map_err(|e| AocError::io(path, io::Error::new(io::ErrorKind::InvalidData, e)));
+    }
+
+    match key {
+        Some(key) => key.decrypt(path, &bytes),
+        None => Err(AocError::Decrypt {
+            path: path.to_path_buf(),
+            message: format!(
+                "it is encrypted, but {} does not exist",
+                key_path().display()
+            ),
+        }),
+    }
+}
+
+/// Reads any input, decrypting it with the cache key if needed
+pub(crate) fn read_input(path: &Path) -> Result<String, AocError> {
+    read_cached(path, CacheKey::load()?.as_ref())
+}
+
+/// Reads a cached input, reporting an [`AocError::CorruptInput`] if it is
+/// not a puzzle input or no longer matches its checksum
+pub(crate) fn read_checked(path: &Path, key: Option<&CacheKey>) -> Result<String, AocError> {
+    let bytes = read(path).map_err(|e| AocError::io(path, e))?;
+    let corrupt = |corruption| AocError::CorruptInput {
+        path: path.to_path_buf(),
+        corruption,
+    };
+
+    if let Ok(saved) = read_to_string(checksum_path(path)) {
+        if saved.trim() != checksum(&bytes) {
+            return Err(corrupt(Corruption::ChecksumMismatch));
+        }
+    }
+    let text = decode(path, bytes, key)?;
+    match corruption(&text) {
+        Some(corruption) => Err(corrupt(corruption)),
+        None => Ok(text),
+    }
+}
+
+/// Downloads the input of `puzzle` again and replaces the cached one, the
+/// cached input is only touched once the download turned out to be valid
+pub fn refresh_input(puzzle: impl Into<Puzzle>) -> Result<String, AocError> {
+    let puzzle = puzzle.into();
+    let key = CacheKey::load()?;
+    download(
+        &AocClient::new()?,
+        key.as_ref(),
+        puzzle,
+        &puzzle.input_path(),
+    )
+}
+
+/// Encrypts the cached input of `puzzle` with the cache key, returning its
+/// path, or `None` if nothing is cached or it already is encrypted
+pub fn encrypt_cached_input(puzzle: impl Into<Puzzle>) -> Result<Option<PathBuf>, AocError> {
+    let Some(path) = puzzle.into().cached_input_path() else {
+        return Ok(None);
+    };
+    let key = CacheKey::load()?.ok_or_else(|| AocError::Decrypt {
+        path: path.clone(),
+        message: format!("{} does not exist", key_path().display()),
+    })?;
+
+    Ok(encrypt_file(&path, &key)?.then_some(path))
+}
+
+/// Replaces a valid plaintext input with its encrypted version
+fn encrypt_file(path: &Path, key: &CacheKey) -> Result<bool, AocError> {
+    let bytes = read(path).map_err(|e| AocError::io(path, e))?;
+    if is_encrypted(&bytes) {
+        return Ok(false);
+    }
+
+    let text = read_checked(path, None)?;
+    save(path, &text, Some(key))?;
+    Ok(true)
+}
+
+/// Downloads the input of `puzzle` with `client` and saves it to `path`
+/// with its checksum, encrypted if there is a `key`
+pub(crate) fn download(
+    client: &AocClient,
+    key: Option<&CacheKey>,
+    puzzle: Puzzle,
+    path: &Path,
+) -> Result<String, AocError> {
+    let text = client.get_text(Some(puzzle), &puzzle.input_url(client.base_url()))?;
+
+    if let Some(corruption) = corruption(&text) {
+        return Err(AocError::CorruptInput {
+            path: path.to_path_buf(),
+            corruption,
+        });
+    }
+    save(path, &text, key)?;
+    Ok(text)
+}
+
+/// Writes the input next to the old one first and then moves it in place,
+/// so an interrupted save never leaves half an input behind.  The checksum
+/// is the one of the bytes on disk, it says nothing about an encrypted
+/// input's plaintext.
+fn save(path: &Path, text: &str, key: Option<&CacheKey>) -> Result<(), AocError> {
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir).map_err(|e| AocError::io(dir, e))?;
+    }
+    let content = match key {
+        Some(key) => key.encrypt(text),
+        None => text.as_bytes().to_vec(),
+    };
+    let sum = checksum(&content);
+    let partial = path.with_extension("partial");
+    write(&partial, content).map_err(|e| AocError::io(&partial, e))?;
+    rename(&partial, path).map_err(|e| AocError::io(path, e))?;
+
+    let sidecar = checksum_path(path);
+    write(&sidecar, format!("{}\n", sum)).map_err(|e| AocError::io(&sidecar, e))
+}
+
+/// Inputs that are obviously not puzzle inputs
+fn corruption(text: &str) -> Option<Corruption> {
+    let start = text.trim_start().to_lowercase();
+
+    if start.is_empty() {
+        Some(Corruption::Empty)
+    } else if start.starts_with("puzzle inputs differ by user") || start.contains("please log in") {
+        Some(Corruption::LoginPrompt)
+    } else if start.starts_with("<!doctype html") || start.starts_with("<html") {
+        Some(Corruption::Html)
+    } else {
+        None
+    }
+}
+
+/// The hex SHA-256 of `bytes`
+fn checksum(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// The checksum sidecar of a cached input, `day_1.txt` has `day_1.sha256`
+fn checksum_path(path: &Path) -> PathBuf {
+    path.with_extension("sha256")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{read, read_to_string, write};
+
+    use mockito::Server;
+    use tempfile::tempdir;
+
+    use crate::{
+        cache::{
+            checksum, checksum_path, corruption, download, encrypt_file, read_checked, Corruption,
+        },
+        client::AocClient,
+        crypt::{is_encrypted, CacheKey},
+        AocError, Puzzle,
+    };
+
+    #[test]
+    fn can_spot_invalid_inputs() {
+        assert!(corruption(" \n") == Some(Corruption::Empty));
+        assert!(
+            corruption("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")
+                == Some(Corruption::LoginPrompt)
+        );
+        assert!(corruption("<!DOCTYPE html>\n<html>500</html>") == Some(Corruption::Html));
+        assert!(corruption("    [D]    \n[N] [C]    \n").is_none());
+        assert!(corruption(">>><<><>><<<>").is_none());
+    }
+
+    #[test]
+    fn can_save_and_verify_a_download() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/2022/day/5/input")
+            .with_body("    [D]\n[N] [C]\n")
+            .create();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("2022/day_5.txt");
+        let client = AocClient::with_base_url(&server.url());
+
+        let text = download(&client, None, Puzzle::new(2022, 5), &path).unwrap();
+
+        mock.assert();
+        assert!(text == "    [D]\n[N] [C]\n");
+        assert!(read_to_string(&path).unwrap() == text);
+        assert!(read_checked(&path, None).unwrap() == text);
+
+        write(&path, "    [D]\n").unwrap();
+        assert!(matches!(
+            read_checked(&path, None),
+            Err(AocError::CorruptInput {
+                corruption: Corruption::ChecksumMismatch,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn keeps_the_old_input_when_the_download_is_invalid() {
+        let mut server = Server::new();
+        server
+            .mock("GET", "/2022/day/1/input")
+            .with_body("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")
+            .create();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("day_1.txt");
+        write(&path, "1000\n2000\n").unwrap();
+        let client = AocClient::with_base_url(&server.url());
+
+        let result = download(&client, None, Puzzle::new(2022, 1), &path);
+
+        assert!(matches!(
+            result,
+            Err(AocError::CorruptInput {
+                corruption: Corruption::LoginPrompt,
+                ..
+            })
+        ));
+        assert!(read_to_string(&path).unwrap() == "1000\n2000\n");
+        assert!(!checksum_path(&path).exists());
+    }
+
+    #[test]
+    fn can_encrypt_cached_inputs() {
+        let mut server = Server::new();
+        server
+            .mock("GET", "/2022/day/6/input")
+            .with_body("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n")
+            .create();
+        let dir = tempdir().unwrap();
+        let (plain, sealed) = (dir.path().join("plain.txt"), dir.path().join("sealed.txt"));
+        let client = AocClient::with_base_url(&server.url());
+        let key = CacheKey::from_secret(b"0123456789abcdef");
+
+        let text = download(&client, Some(&key), Puzzle::new(2022, 6), &sealed).unwrap();
+        write(&plain, &text).unwrap();
+
+        let sealed_bytes = read(&sealed).unwrap();
+        assert!(is_encrypted(&sealed_bytes));
+        assert!(read_checked(&sealed, Some(&key)).unwrap() == text);
+        // the sidecar must not give away a hash of the plaintext
+        let sidecar = read_to_string(checksum_path(&sealed)).unwrap();
+        assert!(sidecar.trim() == checksum(&sealed_bytes));
+        assert!(sidecar.trim() != checksum(text.as_bytes()));
+        assert!(matches!(
+            read_checked(&sealed, None),
+            Err(AocError::Decrypt { .. })
+        ));
+
+        assert!(encrypt_file(&plain, &key).unwrap());
+        assert!(!encrypt_file(&plain, &key).unwrap());
+        assert!(read_checked(&plain, Some(&key)).unwrap() == text);
+    }
+}
@@ -0,0 +1,99 @@
+use reqwest::{
+    blocking::{Client, RequestBuilder},
+    header::{HeaderMap, HeaderValue, COOKIE, USER_AGENT},
+    redirect::Policy,
+};
+
+use crate::{find_session_cookie, throttle::Throttle, AocError, Puzzle};
+
+pub(crate) const BASE_URL: &str = "https://adventofcode.com";
+
+const OFFLINE_ENV: &str = "AOC_OFFLINE";
+
+const USER_AGENT_VALUE: &str = "github.com/mpalmer16/aoc-2022-rs by mpalmer1661@gmail.com";
+
+/// Every request to adventofcode.com goes through here, so that all of them
+/// send the session cookie and respect the [`Throttle`]
+pub(crate) struct AocClient {
+    client: Client,
+    base_url: String,
+    throttle: Throttle,
+}
+
+impl AocClient {
+    /// Fails with [`AocError::Offline`] when `AOC_OFFLINE` is set
+    pub(crate) fn new() -> Result<Self, AocError> {
+        if offline() {
+            return Err(AocError::Offline);
+        }
+        Ok(Self {
+            client: build_client()?,
+            base_url: BASE_URL.to_string(),
+            throttle: Throttle::shared(),
+        })
+    }
+
+    /// A client without cookie or throttle that talks to a mock server
+    #[cfg(test)]
+    pub(crate) fn with_base_url(base_url: &str) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.to_string(),
+            throttle: Throttle::disabled(),
+        }
+    }
+
+    pub(crate) fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// GETs `url` and returns the body, `puzzle` is the puzzle the page
+    /// belongs to (if any) so locked puzzles are never requested
+    pub(crate) fn get_text(&self, puzzle: Option<Puzzle>, url: &str) -> Result<String, AocError> {
+        self.send(puzzle, self.client.get(url))
+    }
+
+    /// POSTs a form to `url` and returns the body
+    pub(crate) fn post_form(
+        &self,
+        puzzle: Option<Puzzle>,
+        url: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, AocError> {
+        self.send(puzzle, self.client.post(url).form(form))
+    }
+
+    fn send(&self, puzzle: Option<Puzzle>, request: RequestBuilder) -> Result<String, AocError> {
+        if let Some(puzzle) = puzzle {
+            self.throttle.check_unlocked(puzzle)?;
+        }
+        self.throttle.wait_turn()?;
+
+        let resp = request.send()?;
+        self.throttle.record(resp.status())?;
+        Ok(resp.error_for_status()?.text()?)
+    }
+}
+
+/// Whether `AOC_OFFLINE` is set (to anything but `0`), nothing is requested
+/// from adventofcode.com then
+pub(crate) fn offline() -> bool {
+    std::env::var_os(OFFLINE_ENV).is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+/// A client that sends the session cookie and our user agent with every request
+fn build_client() -> Result<Client, AocError> {
+    let session_cookie = find_session_cookie()?;
+
+    let cookie_header = HeaderValue::from_str(&format!("session={}", session_cookie))
+        .map_err(|e| AocError::InvalidCookieHeader(e.to_string()))?;
+
+    let mut headers = HeaderMap::new();
+
+    headers.insert(COOKIE, cookie_header);
+    headers.insert(USER_AGENT, HeaderValue::from_static(USER_AGENT_VALUE));
+    Ok(Client::builder()
+        .default_headers(headers)
+        .redirect(Policy::none())
+        .build()?)
+}
@@ -0,0 +1,178 @@
+use std::{
+    fs::{create_dir_all, read, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    Key, XChaCha20Poly1305, XNonce,
+};
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+use crate::{
+    paths::{config_dir, workspace_root},
+    AocError,
+};
+
+const KEY_ENV: &str = "AOC_CACHE_KEY";
+
+/// Every encrypted input starts with this, so they can be told apart from
+/// plaintext ones.  It is authenticated along with the input.
+const MAGIC: &[u8] = b"aoc-2022-rs encrypted input v1\n";
+const NONCE_LEN: usize = 24;
+const KDF_INFO: &[u8] = b"aoc-2022-rs input cache";
+
+/// The key cached inputs are encrypted with, derived from a local secret
+/// file.  Each input is sealed with XChaCha20-Poly1305 under a random nonce.
+pub(crate) struct CacheKey {
+    cipher: XChaCha20Poly1305,
+}
+
+impl CacheKey {
+    /// Loads the key from the secret file, `None` if there is none and
+    /// inputs are cached as plain text
+    pub(crate) fn load() -> Result<Option<Self>, AocError> {
+        let path = key_path();
+        match read(&path) {
+            Ok(secret) => Ok(Some(Self::from_secret(&secret))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(AocError::io(path, e)),
+        }
+    }
+
+    pub(crate) fn from_secret(secret: &[u8]) -> Self {
+        let mut key = Key::default();
+        Hkdf::<Sha256>::new(None, secret.trim_ascii())
+            .expand(KDF_INFO, &mut key)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+
+        Self {
+            cipher: XChaCha20Poly1305::new(&key),
+        }
+    }
+
+    pub(crate) fn encrypt(&self, text: &str) -> Vec<u8> {
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let payload = Payload {
+            msg: text.as_bytes(),
+            aad: MAGIC,
+        };
+        let sealed = self
+            .cipher
+            .encrypt(&nonce, payload)
+            .expect("inputs are far below the XChaCha20-Poly1305 size limit");
+
+        [MAGIC, nonce.as_slice(), &sealed].concat()
+    }
+
+    /// Decrypts what [`CacheKey::encrypt`] wrote to `path`
+    pub(crate) fn decrypt(&self, path: &Path, bytes: &[u8]) -> Result<String, AocError> {
+        let error = |message: &str| AocError::Decrypt {
+            path: path.to_path_buf(),
+            message: message.to_string(),
+        };
+        let body = bytes
+            .strip_prefix(MAGIC)
+            .ok_or_else(|| error("it is not encrypted"))?;
+        if body.len() < NONCE_LEN {
+            return Err(error("it is cut short"));
+        }
+
+        let (nonce, sealed) = body.split_at(NONCE_LEN);
+        let payload = Payload {
+            msg: sealed,
+            aad: MAGIC,
+        };
+        let plain = self
+            .cipher
+            .decrypt(XNonce::from_slice(nonce), payload)
+            .map_err(|_| error("the cache key is wrong or the file was changed"))?;
+        String::from_utf8(plain).map_err(|_| error("it does not decrypt to text"))
+    }
+}
+
+pub(crate) fn is_encrypted(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Where the secret of the cache key is kept: `AOC_CACHE_KEY`, else
+/// `aoc/cache.key` in the user config dir, else `.cache.key` in the
+/// workspace root
+pub(crate) fn key_path() -> PathBuf {
+    std::env::var_os(KEY_ENV)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| config_dir().map(|dir| dir.join("aoc").join("cache.key")))
+        .unwrap_or_else(|| workspace_root().join(".cache.key"))
+}
+
+/// Creates a random secret for the cache key, returning where it was put,
+/// or `None` if there already is one.  Only this machine should ever see it.
+pub fn create_cache_key() -> Result<Option<PathBuf>, AocError> {
+    let path = key_path();
+    if path.exists() {
+        return Ok(None);
+    }
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir).map_err(|e| AocError::io(dir, e))?;
+    }
+
+    let secret: String = XChaCha20Poly1305::generate_key(&mut OsRng)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(&path).map_err(|e| AocError::io(&path, e))?;
+    writeln!(file, "{secret}").map_err(|e| AocError::io(&path, e))?;
+    Ok(Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        crypt::{is_encrypted, CacheKey},
+        AocError,
+    };
+
+    #[test]
+    fn can_encrypt_and_decrypt() {
+        let key = CacheKey::from_secret(b"0123456789abcdef\n");
+        let path = Path::new("day_1.txt");
+
+        let sealed = key.encrypt("1000\n2000\n");
+
+        assert!(is_encrypted(&sealed) && !is_encrypted(b"1000\n2000\n"));
+        assert!(!sealed.windows(4).any(|w| w == b"1000"));
+        assert!(key.encrypt("1000\n2000\n") != sealed);
+        assert!(key.decrypt(path, &sealed).unwrap() == "1000\n2000\n");
+        assert!(
+            CacheKey::from_secret(b"0123456789abcdef")
+                .decrypt(path, &sealed)
+                .unwrap()
+                == "1000\n2000\n"
+        );
+    }
+
+    #[test]
+    fn fail_on_wrong_key_or_tampering() {
+        let key = CacheKey::from_secret(b"0123456789abcdef");
+        let path = Path::new("day_1.txt");
+        let mut sealed = key.encrypt("1000\n2000\n");
+
+        let wrong_key = CacheKey::from_secret(b"fedcba9876543210").decrypt(path, &sealed);
+        *sealed.last_mut().unwrap() ^= 1;
+        let tampered = key.decrypt(path, &sealed);
+
+        assert!(matches!(wrong_key, Err(AocError::Decrypt { .. })));
+        assert!(matches!(tampered, Err(AocError::Decrypt { .. })));
+        assert!(key.decrypt(path, b"1000\n").is_err());
+    }
+}
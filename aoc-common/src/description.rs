@@ -0,0 +1,205 @@
+use std::{
+    fs::{create_dir_all, write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    client::{AocClient, BASE_URL},
+    html::{elements, text, to_markdown},
+    AocError, Puzzle,
+};
+
+/// The puzzle text of a day, converted to Markdown
+///
+/// `parts` only holds part 2 once part 1 has been solved, adventofcode.com
+/// does not show it before that.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Description {
+    pub puzzle: Puzzle,
+    /// e.g. `Day 1: Calorie Counting`
+    pub title: String,
+    /// the Markdown of each part, without its heading
+    pub parts: Vec<String>,
+}
+
+/// Fetches the puzzle page and saves its description as Markdown next to
+/// the cached input (`day_{day}.md`).
+///
+/// The page is always fetched again, part 2 only shows up once part 1 is
+/// solved.
+pub fn fetch_description(puzzle: impl Into<Puzzle>) -> Result<Description, AocError> {
+    let puzzle = puzzle.into();
+    let description = fetch_description_from(&AocClient::new()?, puzzle)?;
+
+    let path = puzzle.description_path();
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir).map_err(|e| AocError::io(dir, e))?;
+    }
+    write(&path, description.to_markdown()).map_err(|e| AocError::io(&path, e))?;
+
+    Ok(description)
+}
+
+pub(crate) fn fetch_description_from(
+    client: &AocClient,
+    puzzle: Puzzle,
+) -> Result<Description, AocError> {
+    let html = client.get_text(Some(puzzle), &puzzle.url(client.base_url()))?;
+
+    parse_description(puzzle, &html)
+}
+
+pub(crate) fn parse_description(puzzle: Puzzle, html: &str) -> Result<Description, AocError> {
+    let articles = elements(html, "<article class=\"day-desc\"", "article");
+    let first = articles
+        .first()
+        .ok_or_else(|| AocError::UnexpectedResponse(text(html)))?;
+
+    let title = elements(first, "<h2", "h2")
+        .first()
+        .map(|heading| text(heading).trim_matches('-').trim().to_string())
+        .unwrap_or_else(|| format!("Day {}", puzzle.day));
+
+    let parts = articles
+        .iter()
+        .map(|article| {
+            let body = article
+                .split_once("</h2>")
+                .map_or(*article, |(_, body)| body);
+            to_markdown(body, BASE_URL)
+        })
+        .collect();
+
+    Ok(Description {
+        puzzle,
+        title,
+        parts,
+    })
+}
+
+impl Description {
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!("# {}\n", self.title);
+        for (idx, part) in self.parts.iter().enumerate() {
+            markdown.push_str(&format!("\n## Part {}\n\n{}\n", idx + 1, part));
+        }
+        markdown
+    }
+
+    /// Writes the `day_{day}` chapter of the book in `book_src`:
+    /// `day_{day}.md` with the title and one page per part.
+    ///
+    /// Pages that already exist are left alone so hand written notes are
+    /// never overwritten.  Returns the pages that were written.
+    pub fn write_book_chapter(&self, book_src: &Path) -> Result<Vec<PathBuf>, AocError> {
+        let day = self.puzzle.day;
+        let chapter = book_src.join(format!("day_{}", day));
+        create_dir_all(&chapter).map_err(|e| AocError::io(&chapter, e))?;
+
+        let title_page = (
+            chapter.join(format!("day_{}.md", day)),
+            format!("# {}\n", self.title),
+        );
+        let part_pages = self.parts.iter().enumerate().map(|(idx, part)| {
+            (
+                chapter.join(format!("day_{}_part_{}.md", day, idx + 1)),
+                format!("# part {}\n\n{}\n", idx + 1, part),
+            )
+        });
+
+        let mut written = vec![];
+        for (path, content) in std::iter::once(title_page).chain(part_pages) {
+            if !path.exists() {
+                write(&path, content).map_err(|e| AocError::io(&path, e))?;
+                written.push(path);
+            }
+        }
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{read_to_string, write};
+
+    use tempfile::tempdir;
+
+    use crate::{
+        client::AocClient,
+        description::{fetch_description_from, parse_description},
+        AocError, Puzzle,
+    };
+
+    const FIXTURE: &str = include_str!("../inputs/day_1_description.html");
+
+    #[test]
+    fn can_parse_both_parts() {
+        let description = parse_description(Puzzle::new(2022, 1), FIXTURE).unwrap();
+
+        assert!(description.title == "Day 1: Calorie Counting");
+        assert!(description.parts.len() == 2);
+        assert!(description.parts[0].starts_with("Santa's reindeer typically eat"));
+        assert!(
+            description.parts[0].contains("[magical energy](https://adventofcode.com/2018/day/25)")
+        );
+        assert!(description.parts[0].contains("```\n1000\n2000\n3000\n\n4000\n"));
+        assert!(description.parts[0]
+            .contains("- The second Elf is carrying one food item with `4000` Calories."));
+        assert!(description.parts[1]
+            .ends_with("__How many Calories are those Elves carrying in total?__"));
+        assert!(!description.parts[1].contains("Your puzzle answer"));
+    }
+
+    #[test]
+    fn can_render_markdown() {
+        let description = parse_description(Puzzle::new(2022, 1), FIXTURE).unwrap();
+        let markdown = description.to_markdown();
+
+        assert!(markdown.starts_with("# Day 1: Calorie Counting\n\n## Part 1\n\nSanta's"));
+        assert!(markdown.contains("\n## Part 2\n\nBy the time"));
+    }
+
+    #[test]
+    fn fail_on_page_without_description() {
+        let result = parse_description(Puzzle::new(2022, 30), "<html><p>404 Not Found</p></html>");
+
+        assert!(matches!(result, Err(AocError::UnexpectedResponse(_))));
+    }
+
+    #[test]
+    fn can_fetch_from_mock_server() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/2022/day/1")
+            .with_body(FIXTURE)
+            .create();
+
+        let description = fetch_description_from(
+            &AocClient::with_base_url(&server.url()),
+            Puzzle::new(2022, 1),
+        );
+
+        mock.assert();
+        assert!(description.unwrap().parts.len() == 2);
+    }
+
+    #[test]
+    fn keeps_existing_book_pages() {
+        let dir = tempdir().unwrap();
+        let description = parse_description(Puzzle::new(2022, 1), FIXTURE).unwrap();
+        std::fs::create_dir_all(dir.path().join("day_1")).unwrap();
+        write(dir.path().join("day_1/day_1_part_1.md"), "# my notes").unwrap();
+
+        let written = description.write_book_chapter(dir.path()).unwrap();
+
+        assert!(written.len() == 2);
+        assert!(read_to_string(dir.path().join("day_1/day_1_part_1.md")).unwrap() == "# my notes");
+        assert!(
+            read_to_string(dir.path().join("day_1/day_1.md")).unwrap()
+                == "# Day 1: Calorie Counting\n"
+        );
+        assert!(read_to_string(dir.path().join("day_1/day_1_part_2.md"))
+            .unwrap()
+            .starts_with("# part 2\n\nBy the time"));
+    }
+}
@@ -0,0 +1,177 @@
+use std::{fmt::Display, io, path::PathBuf, time::Duration};
+
+use crate::{Corruption, Puzzle};
+
+/// Everything that can go wrong while fetching or reading puzzle input
+#[derive(Debug)]
+pub enum AocError {
+    /// no session cookie was found in any of the `tried` locations
+    MissingCookie { tried: Vec<String> },
+    /// the session cookie could not be turned into a request header
+    InvalidCookieHeader(String),
+    /// `AOC_OFFLINE` is set, so adventofcode.com is not contacted
+    Offline,
+    /// the input of the puzzle is not cached and may not be downloaded
+    NotCached(Puzzle),
+    /// adventofcode.com answered with a non-success status code
+    HttpStatus(u16),
+    /// the puzzle is released at midnight EST, `wait` from now
+    NotUnlocked { puzzle: Puzzle, wait: Duration },
+    /// backing off after server errors, try again after `wait`
+    Throttled { wait: Duration },
+    /// the response did not look like anything adventofcode.com usually sends
+    UnexpectedResponse(String),
+    /// the request never got a response (dns, connection, tls, ...)
+    Network(reqwest::Error),
+    /// reading or writing a file on disk failed
+    Io { path: PathBuf, source: io::Error },
+    /// a cached or downloaded input is not a puzzle input
+    CorruptInput {
+        path: PathBuf,
+        corruption: Corruption,
+    },
+    /// an encrypted cached input could not be decrypted
+    Decrypt { path: PathBuf, message: String },
+    /// the puzzle page has no (or not the requested) example block
+    NoExample {
+        puzzle: Puzzle,
+        block: Option<usize>,
+        found: usize,
+    },
+    /// the answer ledger could not be read or written
+    Ledger(String),
+    /// the known answers manifest could not be read
+    KnownAnswers(String),
+    /// a private leaderboard could not be read
+    Leaderboard(String),
+    /// the answer ledger already knows this answer can not be right
+    AnswerRejected { answer: String, reason: String },
+    /// a new day can not be added to the workspace at `path`
+    Scaffold { path: PathBuf, message: String },
+    /// nothing is registered to solve this puzzle
+    NoSolution(Puzzle),
+    /// nothing is registered to solve any puzzle of this year
+    NoSolutions(i32),
+    /// some of several puzzles failed, each one's error is already reported
+    Failed(Vec<Puzzle>),
+    /// the transform rejected a line of the input for `puzzle` (lines start at 1)
+    Transform {
+        puzzle: Puzzle,
+        line: usize,
+        message: String,
+    },
+    /// a [`crate::parse`] parser rejected the input for `puzzle` at `line`
+    /// and `column` (both start at 1)
+    Parse {
+        puzzle: Puzzle,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl AocError {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Self::Io {
+            path: path.into(),
+            source,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingCookie { tried } => {
+                write!(f, "no session cookie found, tried: {}", tried.join(", "))
+            }
+            Self::InvalidCookieHeader(e) => write!(f, "invalid session cookie: {}", e),
+            Self::Offline => write!(f, "AOC_OFFLINE is set, not contacting adventofcode.com"),
+            Self::NotCached(puzzle) => write!(f, "the input of {} is not cached", puzzle),
+            Self::HttpStatus(code) => write!(f, "adventofcode.com responded with status {}", code),
+            Self::NotUnlocked { puzzle, wait } => {
+                write!(f, "{} unlocks in {}s", puzzle, wait.as_secs())
+            }
+            Self::Throttled { wait } => write!(
+                f,
+                "backing off after server errors, try again in {}s",
+                wait.as_secs()
+            ),
+            Self::UnexpectedResponse(text) => write!(f, "unexpected response: {}", text),
+            Self::Network(e) => write!(f, "network error: {}", e),
+            Self::Io { path, source } => {
+                write!(f, "could not access {}: {}", path.display(), source)
+            }
+            Self::CorruptInput { path, corruption } => {
+                write!(f, "{} is not a valid input: {}", path.display(), corruption)
+            }
+            Self::Decrypt { path, message } => {
+                write!(f, "can not decrypt {}: {}", path.display(), message)
+            }
+            Self::NoExample {
+                puzzle,
+                block: Some(block),
+                found,
+            } => write!(
+                f,
+                "{} has no example block {}, found {} blocks",
+                puzzle, block, found
+            ),
+            Self::NoExample { puzzle, .. } => write!(f, "{} has no example block", puzzle),
+            Self::Ledger(e) => write!(f, "invalid answer ledger: {}", e),
+            Self::KnownAnswers(e) => write!(f, "invalid known answers manifest: {}", e),
+            Self::Leaderboard(e) => write!(f, "invalid leaderboard: {}", e),
+            Self::AnswerRejected { answer, reason } => {
+                write!(f, "not submitting {}: {}", answer, reason)
+            }
+            Self::Scaffold { path, message } => {
+                write!(f, "can not add {}: {}", path.display(), message)
+            }
+            Self::NoSolution(puzzle) => write!(f, "there is no solution for {}", puzzle),
+            Self::NoSolutions(year) => write!(f, "there are no solutions for {}", year),
+            Self::Failed(puzzles) => write!(
+                f,
+                "failed: {}",
+                puzzles
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::Transform {
+                puzzle,
+                line,
+                message,
+            } => write!(f, "{} input, line {}: {}", puzzle, line, message),
+            Self::Parse {
+                puzzle,
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "{} input, line {}, column {}: {}",
+                puzzle, line, column, message
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Network(e) => Some(e),
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for AocError {
+    fn from(e: reqwest::Error) -> Self {
+        match e.status() {
+            Some(status) => Self::HttpStatus(status.as_u16()),
+            None => Self::Network(e),
+        }
+    }
+}
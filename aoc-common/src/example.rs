@@ -0,0 +1,181 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    client::AocClient,
+    html::{elements, raw_text, text},
+    AocError, Puzzle,
+};
+
+const PRE_CODE: &str = "<pre><code>";
+const PRE_CODE_END: &str = "</code></pre>";
+
+/// A `<pre><code>` block from a puzzle page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// position among all code blocks on the page, starting at 0
+    pub index: usize,
+    /// the text of the paragraph right before the block
+    pub context: String,
+    pub text: String,
+}
+
+/// Every `<pre><code>` block in the puzzle description, in page order
+pub fn examples(html: &str) -> Vec<Example> {
+    let mut found = vec![];
+
+    for article in elements(html, "<article class=\"day-desc\"", "article") {
+        let mut offset = 0;
+        while let Some(start) = article[offset..].find(PRE_CODE).map(|idx| offset + idx) {
+            let body_start = start + PRE_CODE.len();
+            let Some(end) = article[body_start..].find(PRE_CODE_END) else {
+                break;
+            };
+
+            let before = &article[..start];
+            let context = before
+                .rfind("<p>")
+                .map_or_else(String::new, |p| text(&before[p..]));
+            found.push(Example {
+                index: found.len(),
+                context,
+                text: raw_text(&article[body_start..body_start + end]),
+            });
+            offset = body_start + end + PRE_CODE_END.len();
+        }
+    }
+    found
+}
+
+/// Picks the example input: block `block` when given, otherwise the first
+/// block introduced with "for example" (or similar), falling back to the
+/// largest block on the page
+pub(crate) fn pick_example(examples: &[Example], block: Option<usize>) -> Option<&Example> {
+    if let Some(idx) = block {
+        return examples.get(idx);
+    }
+
+    examples
+        .iter()
+        .find(|example| {
+            let context = example.context.to_lowercase();
+            context.contains("example") || context.contains("for instance")
+        })
+        .or_else(|| examples.iter().max_by_key(|example| example.text.len()))
+}
+
+/// Fetches the puzzle page and writes its example to the day's
+/// `inputs/test_input.txt`, so the `get_test_input` tests have something to
+/// read.
+///
+/// `block` overrides which `<pre><code>` block is used (see [`examples`]).
+/// An existing, non-empty example file is never overwritten.
+pub fn write_example_input(
+    puzzle: impl Into<Puzzle>,
+    block: Option<usize>,
+) -> Result<PathBuf, AocError> {
+    let puzzle = puzzle.into();
+    let client = AocClient::new()?;
+    let html = client.get_text(Some(puzzle), &puzzle.url(client.base_url()))?;
+    let examples = examples(&html);
+
+    let example = pick_example(&examples, block).ok_or(AocError::NoExample {
+        puzzle,
+        block,
+        found: examples.len(),
+    })?;
+
+    let path = puzzle.example_path();
+    write_example(&path, &example.text)?;
+    Ok(path)
+}
+
+/// Reads the example input saved for `puzzle` in `day{day}/inputs/test_input.txt`
+pub fn read_example_input(puzzle: impl Into<Puzzle>) -> Result<String, AocError> {
+    let path = puzzle.into().example_path();
+    read_to_string(&path).map_err(|e| AocError::io(path, e))
+}
+
+fn write_example(path: &Path, text: &str) -> Result<(), AocError> {
+    let existing = read_to_string(path).unwrap_or_default();
+    if !existing.trim().is_empty() {
+        let e = io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "refusing to overwrite an existing example input",
+        );
+        return Err(AocError::io(path, e));
+    }
+
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir).map_err(|e| AocError::io(dir, e))?;
+    }
+    write(path, text.trim_end_matches('\n')).map_err(|e| AocError::io(path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{read_to_string, write};
+
+    use tempfile::tempdir;
+
+    use crate::{
+        example::{examples, pick_example, write_example},
+        AocError,
+    };
+
+    const FIXTURE: &str = include_str!("../inputs/day_1_description.html");
+
+    const MANY_BLOCKS: &str = "<article class=\"day-desc\"><h2>--- Day 5: Supply Stacks ---</h2>\
+        <p>They look like this:</p><pre><code>[A]\n 1 \n</code></pre>\
+        <p>For example:</p><pre><code>    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\n</code></pre>\
+        <p>Then <code>move 1</code> happens:</p><pre><code>[D]\n<em>[N]</em> [C]\n</code></pre></article>";
+
+    #[test]
+    fn can_find_code_blocks() {
+        let found = examples(FIXTURE);
+
+        assert!(found.len() == 1);
+        assert!(found[0]
+            .context
+            .starts_with("For example, suppose the Elves"));
+        assert!(found[0].text.starts_with("1000\n2000\n3000\n\n4000\n"));
+    }
+
+    #[test]
+    fn picks_the_block_introduced_as_an_example() {
+        let found = examples(MANY_BLOCKS);
+        let example = pick_example(&found, None).unwrap();
+
+        assert!(found.len() == 3);
+        assert!(example.index == 1);
+        assert!(example.text.starts_with("    [D]    \n"));
+        assert!(found[2].text == "[D]\n[N] [C]\n");
+    }
+
+    #[test]
+    fn can_override_the_chosen_block() {
+        let found = examples(MANY_BLOCKS);
+
+        assert!(pick_example(&found, Some(2)).unwrap().index == 2);
+        assert!(pick_example(&found, Some(3)).is_none());
+    }
+
+    #[test]
+    fn fills_empty_example_files_only() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("inputs").join("test_input.txt");
+
+        write_example(&path, "1\n2\n").unwrap();
+        assert!(read_to_string(&path).unwrap() == "1\n2");
+
+        let result = write_example(&path, "3");
+        assert!(matches!(result, Err(AocError::Io { .. })));
+
+        write(&path, "").unwrap();
+        write_example(&path, "3").unwrap();
+        assert!(read_to_string(&path).unwrap() == "3");
+    }
+}
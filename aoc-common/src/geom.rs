@@ -0,0 +1,273 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A point on an unbounded plane, `y` grows downwards like the rows of a
+/// [`crate::Grid`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The number of orthogonal steps between the points
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The number of steps between the points when diagonal steps are
+    /// allowed too
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Each coordinate clamped to -1, 0 or 1, a single step towards `self`
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The point one step away in `direction`
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The 4 orthogonal neighbours
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Direction::CARDINAL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// All 8 neighbours, diagonals included
+    pub fn neighbours_with_diagonals(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// The [`crate::Grid`] position of this point, `None` when either
+    /// coordinate is negative
+    pub fn to_position(self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self.step(direction)
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = self.step(direction);
+    }
+}
+
+/// The 4 cardinal and 4 diagonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Up, down, left and right
+    pub const CARDINAL: [Self; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+
+    /// Every direction, clockwise from up
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// The step taken when moving one in this direction
+    pub fn offset(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::UpRight => Point::new(1, -1),
+            Self::Right => Point::new(1, 0),
+            Self::DownRight => Point::new(1, 1),
+            Self::Down => Point::new(0, 1),
+            Self::DownLeft => Point::new(-1, 1),
+            Self::Left => Point::new(-1, 0),
+            Self::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !Self::CARDINAL.contains(&self)
+    }
+
+    /// A quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// A quarter turn counter clockwise
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Turns clockwise in steps of 45 degrees
+    fn rotate(self, eighths: usize) -> Self {
+        let idx = Self::ALL.iter().position(|&d| d == self).unwrap_or(0);
+        Self::ALL[(idx + eighths) % Self::ALL.len()]
+    }
+}
+
+/// Reads `U`, `D`, `L` and `R` (or `^`, `v`, `<` and `>`)
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "^" => Ok(Self::Up),
+            "D" | "v" => Ok(Self::Down),
+            "L" | "<" => Ok(Self::Left),
+            "R" | ">" => Ok(Self::Right),
+            _ => Err(format!("{s:?} is not a direction")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geom::{Direction, Point};
+
+    #[test]
+    fn can_do_point_arithmetic() {
+        let a = Point::new(1, 2);
+        let mut b = Point::new(-3, 5);
+
+        assert!(a + b == Point::new(-2, 7));
+        assert!(a - b == Point::new(4, -3));
+        assert!(a * 3 == Point::new(3, 6));
+        assert!(-a == Point::new(-1, -2));
+        assert!((b - a).signum() == Point::new(-1, 1));
+
+        b += Direction::Up;
+        b -= a;
+        assert!(b == Point::new(-4, 2));
+    }
+
+    #[test]
+    fn can_measure_distances() {
+        let a = Point::new(1, 2);
+        let b = Point::new(-3, 5);
+
+        assert!(a.manhattan(b) == 7);
+        assert!(a.chebyshev(b) == 4);
+        assert!(Point::ORIGIN
+            .neighbours()
+            .all(|p| p.manhattan(Point::ORIGIN) == 1));
+        assert!(Point::ORIGIN
+            .neighbours_with_diagonals()
+            .all(|p| p.chebyshev(Point::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn can_turn() {
+        assert!(Direction::Up.turn_right() == Direction::Right);
+        assert!(Direction::Up.turn_left() == Direction::Left);
+        assert!(Direction::UpLeft.turn_right() == Direction::UpRight);
+        assert!(Direction::DownRight.reverse() == Direction::UpLeft);
+        assert!(Direction::ALL.iter().filter(|d| d.is_diagonal()).count() == 4);
+        assert!(Direction::ALL
+            .iter()
+            .all(|&d| d.offset() + d.reverse().offset() == Point::ORIGIN));
+    }
+
+    #[test]
+    fn can_parse_directions() {
+        assert!("R".parse::<Direction>() == Ok(Direction::Right));
+        assert!("^".parse::<Direction>() == Ok(Direction::Up));
+        assert!("X".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn can_convert_grid_positions() {
+        assert!(Point::from((2, 3)) == Point::new(2, 3));
+        assert!(Point::new(2, 3).to_position() == Some((2, 3)));
+        assert!(Point::new(-1, 3).to_position().is_none());
+    }
+}
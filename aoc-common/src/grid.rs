@@ -0,0 +1,306 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{AocError, Direction, Point, Puzzle};
+
+/// A rectangular grid stored row by row, positions are `(x, y)` with `x`
+/// the column and `y` the row, `(0, 0)` being the top left corner
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// A grid from its rows, `None` if they are not all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+            width,
+        })
+    }
+
+    /// Maps every character of `input` to a cell, one row per line.
+    /// Characters `cell` returns `None` for and ragged lines are reported
+    /// with their line number, like [`crate::transform_lines`] does.
+    pub fn parse<F>(puzzle: Puzzle, input: &str, mut cell: F) -> Result<Self, AocError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut rows = vec![];
+
+        for (idx, line) in input.trim_end().lines().enumerate() {
+            let error = |message: String| AocError::Transform {
+                puzzle,
+                line: idx + 1,
+                message,
+            };
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(col, c)| {
+                    cell(c).ok_or_else(|| error(format!("unexpected {c:?} in column {}", col + 1)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if let Some(first) = rows.first().map(Vec::len).filter(|&w| w != row.len()) {
+                return Err(error(format!("{} cells, expected {}", row.len(), first)));
+            }
+            rows.push(row);
+        }
+
+        Ok(Self::from_rows(rows).expect("rows are checked while parsing"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Whether `pos` lies on the outer border of the grid
+    pub fn is_edge(&self, (x, y): (usize, usize)) -> bool {
+        self.contains((x, y)) && (x == 0 || y == 0 || x == self.width - 1 || y == self.height - 1)
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Replaces the cell at `pos`, returning the previous value or `None`
+    /// when `pos` is outside the grid
+    pub fn set(&mut self, pos: (usize, usize), value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every position with its cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position whose cell matches `predicate`
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// The cells of row `y`, empty if it is outside the grid
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        let row = if y < self.height {
+            &self.cells[y * self.width..(y + 1) * self.width]
+        } else {
+            &[]
+        };
+        row.iter()
+    }
+
+    /// The cells of column `x`, empty if it is outside the grid
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The positions and cells seen when walking from `start` in
+    /// `direction` until leaving the grid, `start` itself not included
+    pub fn ray(
+        &self,
+        start: (usize, usize),
+        direction: Direction,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        std::iter::successors(self.step(start, direction), move |&pos| {
+            self.step(pos, direction)
+        })
+        .map(|pos| (pos, &self[pos]))
+    }
+
+    /// The up to 4 orthogonal neighbours of `pos` inside the grid
+    pub fn neighbours(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The up to 8 neighbours of `pos` inside the grid, diagonals included
+    pub fn neighbours_with_diagonals(
+        &self,
+        pos: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The position one step from `pos` in `direction`, if inside the grid
+    pub fn step(&self, pos: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        Point::from(pos)
+            .step(direction)
+            .to_position()
+            .filter(|&next| self.contains(next))
+    }
+
+    /// A grid of the same shape with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn index_of(&self, (x, y): (usize, usize)) -> Option<usize> {
+        self.contains((x, y)).then(|| y * self.width + x)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+/// Renders the cells of each row next to each other, one row per line
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{grid::Grid, AocError, Direction, Puzzle};
+
+    const PUZZLE: Puzzle = Puzzle::new(2022, 8);
+
+    fn digits() -> Grid<u32> {
+        Grid::parse(PUZZLE, "123\n456\n789\n", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn can_parse_and_render() {
+        let grid = digits();
+
+        assert!(grid.width() == 3 && grid.height() == 3);
+        assert!(grid[(2, 0)] == 3 && grid[(0, 2)] == 7);
+        assert!(grid.to_string() == "123\n456\n789");
+        assert!(
+            grid.map(|&d| if d % 2 == 0 { '#' } else { '.' })
+                .to_string()
+                == ".#.\n#.#\n.#."
+        );
+    }
+
+    #[test]
+    fn fail_on_bad_cells_and_ragged_rows() {
+        let bad = Grid::parse(PUZZLE, "12\n3x\n", |c| c.to_digit(10));
+        let ragged = Grid::parse(PUZZLE, "12\n3\n", |c| c.to_digit(10));
+
+        assert!(matches!(
+            bad,
+            Err(AocError::Transform { line: 2, message, .. }) if message == "unexpected 'x' in column 2"
+        ));
+        assert!(matches!(ragged, Err(AocError::Transform { line: 2, .. })));
+        assert!(Grid::from_rows(vec![vec![1], vec![]]).is_none());
+    }
+
+    #[test]
+    fn can_get_and_set_within_bounds() {
+        let mut grid = digits();
+
+        assert!(grid.get((3, 0)).is_none());
+        assert!(grid.set((1, 1), 0) == Some(5));
+        assert!(grid.set((1, 3), 0).is_none());
+        assert!(grid[(1, 1)] == 0);
+        assert!(grid.find(|&d| d == 8) == Some((1, 2)));
+    }
+
+    #[test]
+    fn can_walk_rows_columns_and_rays() {
+        let grid = digits();
+
+        assert!(grid.row(1).copied().collect::<Vec<_>>() == [4, 5, 6]);
+        assert!(grid.column(2).copied().collect::<Vec<_>>() == [3, 6, 9]);
+        assert!(grid.row(3).count() == 0 && grid.column(3).count() == 0);
+        assert!(grid.rows().count() == 3);
+
+        let ray = grid.ray((0, 0), Direction::DownRight).collect::<Vec<_>>();
+        assert!(ray == [((1, 1), &5), ((2, 2), &9)]);
+        assert!(
+            grid.ray((1, 1), Direction::Up)
+                .map(|(_, &d)| d)
+                .collect::<Vec<_>>()
+                == [2]
+        );
+        assert!(grid.ray((0, 1), Direction::Left).count() == 0);
+    }
+
+    #[test]
+    fn can_find_neighbours() {
+        let grid = digits();
+
+        assert!(grid.neighbours((0, 0)).collect::<Vec<_>>() == [(0, 1), (1, 0)]);
+        assert!(grid.neighbours((1, 1)).count() == 4);
+        assert!(grid.neighbours_with_diagonals((0, 0)).count() == 3);
+        assert!(grid.neighbours_with_diagonals((1, 1)).count() == 8);
+        assert!(grid.is_edge((2, 1)) && !grid.is_edge((1, 1)));
+    }
+}
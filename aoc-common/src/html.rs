@@ -0,0 +1,250 @@
+//! Just enough html handling for the pages adventofcode.com serves
+//!
+//! Puzzle pages only use a handful of tags (`article`, `h2`, `p`, `em`,
+//! `code`, `pre`, `ul`, `li`, `a`, `span`), so a full html parser is not
+//! needed to read them.
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    /// an opening tag, with its name and the raw attribute string
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Splits html into tags and the text between them
+pub(crate) fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                let Some(end) = rest.find('>') else {
+                    tokens.push(Token::Text(rest));
+                    break;
+                };
+                let tag = rest[1..end].trim_end_matches('/').trim();
+                if let Some(name) = tag.strip_prefix('/') {
+                    tokens.push(Token::Close(name.trim()));
+                } else if !tag.starts_with('!') {
+                    let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                    tokens.push(Token::Open(name, attrs));
+                }
+                rest = &rest[end + 1..];
+            }
+            Some(start) => {
+                tokens.push(Token::Text(&rest[..start]));
+                rest = &rest[start..];
+            }
+            None => {
+                tokens.push(Token::Text(rest));
+                break;
+            }
+        }
+    }
+    tokens
+}
+
+/// The inner html of every `<tag ...>` element whose opening tag starts
+/// with `open`, e.g. `elements(html, "<article class=\"day-desc\"", "article")`
+///
+/// Elements of the same tag nested inside each other are not supported.
+pub(crate) fn elements<'a>(html: &'a str, open: &str, tag: &str) -> Vec<&'a str> {
+    let close = format!("</{}>", tag);
+    let mut found = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        let after_open = &rest[start + open.len()..];
+        let Some(body_start) = after_open.find('>') else {
+            break;
+        };
+        let body = &after_open[body_start + 1..];
+        let Some(end) = body.find(&close) else {
+            break;
+        };
+        found.push(&body[..end]);
+        rest = &body[end + close.len()..];
+    }
+    found
+}
+
+/// The value of `name="..."` in a raw attribute string
+pub(crate) fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let (_, rest) = attrs.split_once(&format!("{}=\"", name))?;
+    rest.split_once('"').map(|(value, _)| value)
+}
+
+pub(crate) fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The text of an html fragment without any markup, whitespace collapsed
+pub(crate) fn text(html: &str) -> String {
+    raw_text(html)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The text of an html fragment without any markup, whitespace untouched
+pub(crate) fn raw_text(html: &str) -> String {
+    tokens(html)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(t) => Some(decode_entities(t)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Converts an html fragment from a puzzle page to Markdown.
+///
+/// Emphasis becomes `__bold__` like in the book, relative links are made
+/// absolute with `base_url`.
+pub(crate) fn to_markdown(html: &str, base_url: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut links: Vec<String> = vec![];
+
+    for token in tokens(html) {
+        match token {
+            Token::Text(t) if in_pre => out.push_str(&decode_entities(t)),
+            Token::Text(t) => {
+                let t = decode_entities(t);
+                let collapsed = t.split_whitespace().collect::<Vec<_>>().join(" ");
+                if t.starts_with(char::is_whitespace) && !out.ends_with(['\n', ' ']) {
+                    out.push(' ');
+                }
+                out.push_str(&collapsed);
+                if t.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                    out.push(' ');
+                }
+            }
+            Token::Open("h2", _) => out.push_str("## "),
+            Token::Open("pre", _) => {
+                in_pre = true;
+                out.push_str("\n```\n");
+            }
+            Token::Close("pre") => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            Token::Open("code", _) if !in_pre => {
+                in_code = true;
+                out.push('`');
+            }
+            Token::Close("code") if !in_pre => {
+                in_code = false;
+                out.push('`');
+            }
+            Token::Open("em", _) | Token::Close("em") if !in_pre && !in_code => out.push_str("__"),
+            Token::Open("li", _) => out.push_str("- "),
+            Token::Open("a", attrs) => {
+                let href = attribute(attrs, "href").unwrap_or_default();
+                let href = if href.starts_with('/') {
+                    format!("{}{}", base_url, href)
+                } else {
+                    href.to_string()
+                };
+                links.push(href);
+                out.push('[');
+            }
+            Token::Close("a") => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({})", href));
+            }
+            Token::Close("h2") | Token::Close("p") | Token::Close("ul") => out.push_str("\n\n"),
+            Token::Close("li") => out.push('\n'),
+            _ => {}
+        }
+    }
+
+    tidy(&out)
+}
+
+/// Trims every line outside code blocks and drops repeated blank lines
+fn tidy(markdown: &str) -> String {
+    let mut lines: Vec<&str> = vec![];
+    let mut in_fence = false;
+
+    for line in markdown.lines() {
+        if line.trim() == "```" {
+            in_fence = !in_fence;
+        }
+        let line = if in_fence { line } else { line.trim() };
+        if line.is_empty() && lines.last().is_none_or(|l| l.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::{elements, text, to_markdown, tokens, Token};
+
+    #[test]
+    fn can_tokenize() {
+        let tokens = tokens("<p class=\"x\">a <em>b</em></p><br/>");
+
+        assert!(
+            tokens
+                == vec![
+                    Token::Open("p", "class=\"x\""),
+                    Token::Text("a "),
+                    Token::Open("em", ""),
+                    Token::Text("b"),
+                    Token::Close("em"),
+                    Token::Close("p"),
+                    Token::Open("br", ""),
+                ]
+        );
+    }
+
+    #[test]
+    fn can_find_elements() {
+        let html = "<article class=\"day-desc\"><p>one</p></article><article><p>x</p></article>\
+                    <article class=\"day-desc\"><p>two</p></article>";
+
+        let found = elements(html, "<article class=\"day-desc\"", "article");
+
+        assert!(found == vec!["<p>one</p>", "<p>two</p>"]);
+    }
+
+    #[test]
+    fn can_get_text() {
+        assert!(
+            text("<p>That's <em>not</em>\n the &quot;answer&quot;</p>")
+                == "That's not the \"answer\""
+        );
+    }
+
+    #[test]
+    fn can_convert_to_markdown() {
+        let html = "<p>Find the <em>top</em> <a href=\"/2022/day/1\">Elf</a> with <code>x &lt; 3</code>:</p>\n\
+                    <pre><code>1000\n<em>2000</em>\n</code></pre>\n\
+                    <ul>\n<li>one</li>\n<li>two</li>\n</ul>";
+
+        let markdown = to_markdown(html, "https://adventofcode.com");
+
+        assert!(
+            markdown
+                == "Find the __top__ [Elf](https://adventofcode.com/2022/day/1) with `x < 3`:\n\n\
+                    ```\n1000\n2000\n```\n\n\
+                    - one\n- two"
+        );
+    }
+}
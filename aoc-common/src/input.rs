@@ -0,0 +1,136 @@
+use std::{
+    fmt::Display,
+    fs::read_to_string,
+    io::{self, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::{read_example_input, try_fetch_with_transform, AocError, Puzzle};
+
+/// How a day binary gets its input: `dayN [--example | --input <FILE|->]`
+pub(crate) const USAGE: &str = "[--example | --input <FILE|->]";
+
+/// Where the puzzle input is read from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// the cached input, downloaded first if there is none yet
+    #[default]
+    Cached,
+    /// any file, like someone else's input
+    File(PathBuf),
+    /// whatever is piped in
+    Stdin,
+    /// the example in `day{N}/inputs/test_input.txt`
+    Example,
+}
+
+impl InputSource {
+    /// Picks the source from command line arguments (without the program
+    /// name): `--example`, or `--input` with a file or `-` for stdin
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        let mut source = Self::Cached;
+
+        while let Some(arg) = args.next() {
+            let next = match arg.as_str() {
+                "--example" => Self::Example,
+                "--input" => args
+                    .next()
+                    .ok_or("--input needs a file, or - for stdin")?
+                    .parse()?,
+                _ => return Err(format!("unexpected argument {arg:?}")),
+            };
+            if source != Self::Cached {
+                return Err("only one of --example and --input can be given".to_string());
+            }
+            source = next;
+        }
+        Ok(source)
+    }
+
+    /// Reads the whole input of `puzzle` from this source
+    pub fn read(&self, puzzle: impl Into<Puzzle>) -> Result<String, AocError> {
+        let puzzle = puzzle.into();
+        match self {
+            Self::Cached => try_fetch_with_transform(puzzle, |s| s),
+            Self::File(path) => read_to_string(path).map_err(|e| AocError::io(path, e)),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| AocError::io("<stdin>", e))?;
+                Ok(input)
+            }
+            Self::Example => read_example_input(puzzle),
+        }
+    }
+}
+
+/// `-` is stdin, anything else a file
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("an empty path is not an input".to_string()),
+            "-" => Ok(Self::Stdin),
+            path => Ok(Self::File(PathBuf::from(path))),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cached => write!(f, "the cached input"),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+            Self::Example => write!(f, "the example"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::write, path::PathBuf};
+
+    use tempfile::tempdir;
+
+    use crate::{input::InputSource, AocError, Puzzle};
+
+    fn args(args: &[&str]) -> Result<InputSource, String> {
+        InputSource::from_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn can_pick_the_source_from_arguments() {
+        assert!(args(&[]) == Ok(InputSource::Cached));
+        assert!(args(&["--example"]) == Ok(InputSource::Example));
+        assert!(args(&["--input", "-"]) == Ok(InputSource::Stdin));
+        assert!(
+            args(&["--input", "other.txt"]) == Ok(InputSource::File(PathBuf::from("other.txt")))
+        );
+    }
+
+    #[test]
+    fn fail_on_bad_arguments() {
+        assert!(args(&["--input"]).is_err());
+        assert!(args(&["--input", ""]).is_err());
+        assert!(args(&["--example", "--input", "-"]).is_err());
+        assert!(args(&["--part", "1"]) == Err("unexpected argument \"--part\"".to_string()));
+    }
+
+    #[test]
+    fn can_read_any_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("other.txt");
+        write(&path, "1000\n2000\n").unwrap();
+
+        let input = InputSource::File(path).read(Puzzle::new(2022, 1)).unwrap();
+        let missing = InputSource::File(dir.path().join("missing.txt")).read(Puzzle::new(2022, 1));
+
+        assert!(input == "1000\n2000\n");
+        assert!(matches!(missing, Err(AocError::Io { .. })));
+    }
+}
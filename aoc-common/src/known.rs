@@ -0,0 +1,282 @@
+use std::{
+    fmt::Display,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{cache::read_input, AocError, Day, Part, Puzzle};
+
+/// The answers a day is known to produce, stored in
+/// `day{day}/known_answers.toml`:
+///
+/// ```toml
+/// [[case]]
+/// input = "example"        # day{day}/inputs/test_input.txt
+/// part1 = 24000
+/// part2 = 45000
+///
+/// [[case]]
+/// input = "cached"         # the downloaded input, skipped when not cached
+/// part1 = 69000
+///
+/// [[case]]
+/// input = "inputs/test_input_2.txt"
+/// part2 = 36
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownAnswers {
+    pub puzzle: Puzzle,
+    pub cases: Vec<Case>,
+}
+
+/// One input and the answers expected for it, a part without an answer is
+/// not checked
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Case {
+    /// `example`, `cached` or a file relative to the day's crate
+    pub input: String,
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+}
+
+/// Answers can be written as numbers or strings in the manifest
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    #[serde(default, rename = "case")]
+    cases: Vec<Case>,
+}
+
+/// How one part of one case turned out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Matched,
+    Changed {
+        expected: String,
+        actual: String,
+    },
+    /// the real input is not cached
+    Skipped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub puzzle: Puzzle,
+    pub input: String,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+impl KnownAnswers {
+    /// Loads the manifest of the day of `puzzle`, `None` if it has none
+    pub fn load(puzzle: impl Into<Puzzle>) -> Result<Option<Self>, AocError> {
+        let puzzle = puzzle.into();
+        Self::load_from(puzzle, &puzzle.known_answers_path())
+    }
+
+    pub(crate) fn load_from(puzzle: Puzzle, path: &Path) -> Result<Option<Self>, AocError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = read_to_string(path).map_err(|e| AocError::io(path, e))?;
+        let manifest: Manifest = toml::from_str(&content)
+            .map_err(|e| AocError::KnownAnswers(format!("{}: {}", path.display(), e)))?;
+
+        Ok(Some(Self {
+            puzzle,
+            cases: manifest.cases,
+        }))
+    }
+
+    /// Solves every case with `day` and compares the answers
+    pub fn check(&self, day: &Day) -> Result<Vec<Check>, AocError> {
+        self.check_in(day, &self.puzzle.crate_dir())
+    }
+
+    pub(crate) fn check_in(&self, day: &Day, crate_dir: &Path) -> Result<Vec<Check>, AocError> {
+        let mut checks = vec![];
+
+        for case in &self.cases {
+            let expected = [(Part::One, &case.part1), (Part::Two, &case.part2)]
+                .into_iter()
+                .filter_map(|(part, expected)| expected.as_ref().map(|e| (part, e.to_string())))
+                .collect::<Vec<_>>();
+            let check = |part, outcome| Check {
+                puzzle: self.puzzle,
+                input: case.input.clone(),
+                part,
+                outcome,
+            };
+
+            let Some(path) = self.input_path(&case.input, crate_dir) else {
+                checks.extend(
+                    expected
+                        .iter()
+                        .map(|(part, _)| check(*part, Outcome::Skipped)),
+                );
+                continue;
+            };
+            let input = read_input(&path)?;
+            let parts = expected.iter().map(|(part, _)| *part).collect::<Vec<_>>();
+            let solved = day.solve(input, &parts)?;
+
+            for ((part, expected), answer) in expected.into_iter().zip(solved.answers) {
+                let outcome = if same_answer(&expected, &answer.value) {
+                    Outcome::Matched
+                } else {
+                    Outcome::Changed {
+                        expected,
+                        actual: answer.value,
+                    }
+                };
+                checks.push(check(part, outcome));
+            }
+        }
+        Ok(checks)
+    }
+
+    fn input_path(&self, input: &str, crate_dir: &Path) -> Option<PathBuf> {
+        match input {
+            "example" => Some(crate_dir.join("inputs").join("test_input.txt")),
+            "cached" => self.puzzle.cached_input_path(),
+            file => Some(crate_dir.join(file)),
+        }
+    }
+}
+
+/// Answers are compared line by line without trailing whitespace, so
+/// rendered screens survive being pasted into the manifest
+fn same_answer(expected: &str, actual: &str) -> bool {
+    expected
+        .trim()
+        .lines()
+        .map(str::trim_end)
+        .eq(actual.trim().lines().map(str::trim_end))
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}, {}: ", self.puzzle, self.input, self.part)?;
+        match &self.outcome {
+            Outcome::Matched => write!(f, "ok"),
+            Outcome::Skipped => write!(f, "skipped, the input is not cached"),
+            Outcome::Changed { expected, actual }
+                if !expected.contains('\n') && !actual.contains('\n') =>
+            {
+                write!(f, "expected {}, got {}", expected, actual)
+            }
+            Outcome::Changed { expected, actual } => {
+                writeln!(f, "the answer changed")?;
+                write!(f, "{}", diff(expected, actual))
+            }
+        }
+    }
+}
+
+/// A line by line diff, `-` for expected and `+` for actual lines
+fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected
+        .trim()
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>();
+    let actual = actual.trim().lines().map(str::trim_end).collect::<Vec<_>>();
+    let mut out = vec![];
+
+    for idx in 0..expected.len().max(actual.len()) {
+        match (expected.get(idx), actual.get(idx)) {
+            (Some(e), Some(a)) if e == a => out.push(format!("  {}", e)),
+            (e, a) => {
+                out.extend(e.map(|e| format!("- {}", e)));
+                out.extend(a.map(|a| format!("+ {}", a)));
+            }
+        }
+    }
+    out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{create_dir_all, write};
+
+    use tempfile::tempdir;
+
+    use crate::{
+        known::{diff, KnownAnswers, Outcome},
+        solution::Sums,
+        AocError, Day, Part, Solution,
+    };
+
+    #[test]
+    fn can_check_known_answers() {
+        let dir = tempdir().unwrap();
+        create_dir_all(dir.path().join("inputs")).unwrap();
+        write(dir.path().join("inputs/test_input.txt"), "1\n2\n").unwrap();
+        write(dir.path().join("inputs/other.txt"), "5").unwrap();
+        let manifest = dir.path().join("known_answers.toml");
+        write(
+            &manifest,
+            "[[case]]\ninput = \"example\"\npart1 = 3\npart2 = \"\"\"\n1\n2\n\"\"\"\n\n\
+             [[case]]\ninput = \"inputs/other.txt\"\npart1 = \"6\"\n\n\
+             [[case]]\ninput = \"cached\"\npart2 = 1\n",
+        )
+        .unwrap();
+
+        let known = KnownAnswers::load_from(Sums::PUZZLE, &manifest)
+            .unwrap()
+            .unwrap();
+        let checks = known.check_in(&Day::of::<Sums>(), dir.path()).unwrap();
+
+        assert!(checks.len() == 4);
+        assert!(checks[0].outcome == Outcome::Matched && checks[0].part == Part::One);
+        assert!(checks[1].outcome == Outcome::Matched);
+        assert!(
+            checks[2].outcome
+                == Outcome::Changed {
+                    expected: "6".to_string(),
+                    actual: "5".to_string()
+                }
+        );
+        assert!(checks[2].to_string() == "2015 day 3, inputs/other.txt, part 1: expected 6, got 5");
+        assert!(checks[3].outcome == Outcome::Skipped);
+    }
+
+    #[test]
+    fn reports_missing_and_broken_manifests() {
+        let dir = tempdir().unwrap();
+        let manifest = dir.path().join("known_answers.toml");
+
+        assert!(KnownAnswers::load_from(Sums::PUZZLE, &manifest)
+            .unwrap()
+            .is_none());
+
+        write(&manifest, "[[case]]\npart1 = 3\n").unwrap();
+        assert!(matches!(
+            KnownAnswers::load_from(Sums::PUZZLE, &manifest),
+            Err(AocError::KnownAnswers(_))
+        ));
+    }
+
+    #[test]
+    fn can_diff_multi_line_answers() {
+        assert!(diff("##..\n#..#\n", "##..\n#.##") == "  ##..\n- #..#\n+ #.##");
+    }
+}
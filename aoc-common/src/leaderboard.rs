@@ -0,0 +1,321 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+    fs::{metadata, read_to_string, write},
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use serde::Deserialize;
+
+use crate::{client::AocClient, paths::workspace_root, AocError};
+
+/// adventofcode.com asks not to fetch a leaderboard more often than this
+const MIN_REFRESH: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard, members ordered by their local score
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    /// the year, like `2022`
+    pub event: String,
+    pub owner_id: u64,
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// `None` for anonymous users
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    #[serde(default)]
+    pub global_score: u64,
+    /// seconds since the unix epoch, 0 without any stars
+    pub last_star_ts: u64,
+    /// the stars of every day with at least one
+    #[serde(rename = "completion_day_level")]
+    pub days: BTreeMap<u32, DayStars>,
+}
+
+/// The stars of one member on one day
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DayStars {
+    #[serde(rename = "1")]
+    pub part1: Star,
+    #[serde(rename = "2")]
+    pub part2: Option<Star>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    /// seconds since the unix epoch
+    #[serde(rename = "get_star_ts")]
+    pub solved_at: u64,
+}
+
+#[derive(Deserialize)]
+struct LeaderboardFile {
+    event: String,
+    owner_id: u64,
+    members: HashMap<String, Member>,
+}
+
+/// Fetches private leaderboard `id` of `year`.  The JSON is cached in the
+/// workspace root and only fetched again once it is 15 minutes old, with
+/// `AOC_OFFLINE` set an older copy is used as well.
+pub fn fetch_leaderboard(year: i32, id: u64) -> Result<Leaderboard, AocError> {
+    let path = workspace_root().join(format!(".aoc-leaderboard-{}-{}.json", year, id));
+    fetch_leaderboard_from(AocClient::new, &path, year, id, SystemTime::now())
+}
+
+/// The client is only created when the cache in `path` is missing or
+/// older than 15 minutes at `now`
+pub(crate) fn fetch_leaderboard_from(
+    client: impl FnOnce() -> Result<AocClient, AocError>,
+    path: &Path,
+    year: i32,
+    id: u64,
+    now: SystemTime,
+) -> Result<Leaderboard, AocError> {
+    if is_fresh(path, now) {
+        return Leaderboard::parse(&read_to_string(path).map_err(|e| AocError::io(path, e))?);
+    }
+
+    let client = match client() {
+        Err(AocError::Offline) if path.exists() => {
+            let json = read_to_string(path).map_err(|e| AocError::io(path, e))?;
+            return Leaderboard::parse(&json);
+        }
+        client => client?,
+    };
+    let url = format!(
+        "{}/{}/leaderboard/private/view/{}.json",
+        client.base_url(),
+        year,
+        id
+    );
+    let json = client.get_text(None, &url)?;
+
+    let leaderboard = Leaderboard::parse(&json)?;
+    write(path, json).map_err(|e| AocError::io(path, e))?;
+    Ok(leaderboard)
+}
+
+fn is_fresh(path: &Path, now: SystemTime) -> bool {
+    metadata(path)
+        .and_then(|meta| meta.modified())
+        .is_ok_and(|modified| {
+            now.duration_since(modified)
+                .map_or(true, |age| age < MIN_REFRESH)
+        })
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, AocError> {
+        let file: LeaderboardFile = serde_json::from_str(json).map_err(|e| {
+            AocError::Leaderboard(format!(
+                "{} (is the session cookie valid and a member of the leaderboard?)",
+                e
+            ))
+        })?;
+
+        let mut members = file.members.into_values().collect::<Vec<_>>();
+        members.sort_by_key(|m| (Reverse(m.local_score), Reverse(m.stars), m.id));
+        Ok(Self {
+            event: file.event,
+            owner_id: file.owner_id,
+            members,
+        })
+    }
+
+    /// The members by score, with how long each took from part 1 to part 2
+    /// of every day anyone has a star for (`*` if only part 1 is solved)
+    pub fn standings(&self) -> String {
+        let last_day = self
+            .members
+            .iter()
+            .filter_map(|m| m.days.keys().last())
+            .max()
+            .copied()
+            .unwrap_or(0);
+
+        let mut rows = vec![["#", "member", "score", "stars"]
+            .into_iter()
+            .map(String::from)
+            .chain((1..=last_day).map(|day| day.to_string()))
+            .collect::<Vec<_>>()];
+        for (idx, member) in self.members.iter().enumerate() {
+            let days = (1..=last_day).map(|day| match member.days.get(&day) {
+                Some(stars) => stars.delta().map_or("*".to_string(), format_delta),
+                None => String::new(),
+            });
+            rows.push(
+                [
+                    format!("{})", idx + 1),
+                    member.display_name(),
+                    member.local_score.to_string(),
+                    member.stars.to_string(),
+                ]
+                .into_iter()
+                .chain(days)
+                .collect(),
+            );
+        }
+
+        let widths = (0..rows[0].len())
+            .map(|col| {
+                rows.iter()
+                    .map(|row| row[col].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<usize>>();
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .zip(&widths)
+                    .enumerate()
+                    .map(|(col, (cell, &width))| match col {
+                        1 => format!("{:<width$}", cell),
+                        _ => format!("{:>width$}", cell),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("  ")
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+}
+
+impl DayStars {
+    /// How long it took from the first star of the day to the second one
+    pub fn delta(&self) -> Option<Duration> {
+        self.part2
+            .map(|part2| Duration::from_secs(part2.solved_at.saturating_sub(self.part1.solved_at)))
+    }
+}
+
+/// `4m05s`, `1h20m` or `2d03h`
+fn format_delta(delta: Duration) -> String {
+    let secs = delta.as_secs();
+    match secs {
+        0..=3599 => format!("{}m{:02}s", secs / 60, secs % 60),
+        3600..=86399 => format!("{}h{:02}m", secs / 3600, secs % 3600 / 60),
+        _ => format!("{}d{:02}h", secs / 86400, secs % 86400 / 3600),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::write,
+        time::{Duration, SystemTime},
+    };
+
+    use mockito::Server;
+    use tempfile::tempdir;
+
+    use crate::{
+        client::AocClient,
+        leaderboard::{fetch_leaderboard_from, Leaderboard},
+        AocError,
+    };
+
+    const FIXTURE: &str = include_str!("../inputs/leaderboard.json");
+    const NO_STARS: &str = include_str!("../inputs/leaderboard_no_stars.json");
+
+    #[test]
+    fn can_parse_a_leaderboard() {
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+        let names = leaderboard
+            .members
+            .iter()
+            .map(|m| m.display_name())
+            .collect::<Vec<_>>();
+        let ada = &leaderboard.members[0];
+
+        assert!(leaderboard.event == "2022" && leaderboard.owner_id == 101);
+        assert!(names == ["Ada", "Grace", "(anonymous user #303)"]);
+        assert!(ada.stars == 5 && ada.local_score == 15);
+        assert!(ada.days[&1].part1.solved_at == 1669871100);
+        assert!(ada.days[&1].delta() == Some(Duration::from_secs(300)));
+        assert!(ada.days[&3].part2.is_none() && ada.days[&3].delta().is_none());
+        assert!(matches!(
+            Leaderboard::parse("<html>log in</html>"),
+            Err(AocError::Leaderboard(_))
+        ));
+    }
+
+    #[test]
+    fn can_render_standings() {
+        let standings = Leaderboard::parse(FIXTURE).unwrap().standings();
+
+        assert!(
+            standings
+                == [
+                    " #  member                 score  stars      1       2  3",
+                    "1)  Ada                       15      5  5m00s  10m00s  *",
+                    "2)  Grace                      8      4  1h00m   1d00h",
+                    "3)  (anonymous user #303)      0      0",
+                ]
+                .join("\n")
+        );
+        assert!(
+            Leaderboard::parse(NO_STARS).unwrap().standings()
+                == " #  member  score  stars\n1)  Linus       0      0"
+        );
+    }
+
+    #[test]
+    fn can_cache_for_15_minutes() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/2022/leaderboard/private/view/101.json")
+            .with_body(FIXTURE)
+            .expect(2)
+            .create();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("leaderboard.json");
+        let client = || Ok(AocClient::with_base_url(&server.url()));
+        let now = SystemTime::now();
+
+        let fetched = fetch_leaderboard_from(client, &path, 2022, 101, now).unwrap();
+        let cached = fetch_leaderboard_from(client, &path, 2022, 101, now).unwrap();
+        let later = now + Duration::from_secs(16 * 60);
+        let refreshed = fetch_leaderboard_from(client, &path, 2022, 101, later).unwrap();
+
+        mock.assert();
+        assert!(fetched == cached && cached == refreshed);
+    }
+
+    #[test]
+    fn keeps_the_cache_when_the_response_is_not_a_leaderboard() {
+        let mut server = Server::new();
+        server
+            .mock("GET", "/2022/leaderboard/private/view/101.json")
+            .with_status(302)
+            .create();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("leaderboard.json");
+        write(&path, NO_STARS).unwrap();
+        let client = || Ok(AocClient::with_base_url(&server.url()));
+        let later = SystemTime::now() + Duration::from_secs(16 * 60);
+
+        let result = fetch_leaderboard_from(client, &path, 2022, 101, later);
+        let offline = fetch_leaderboard_from(|| Err(AocError::Offline), &path, 2022, 101, later);
+
+        assert!(matches!(result, Err(AocError::Leaderboard(_))));
+        assert!(offline.unwrap() == Leaderboard::parse(NO_STARS).unwrap());
+    }
+}
@@ -0,0 +1,255 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AocError, Part, Puzzle, Verdict};
+
+/// Every answer submitted for one day, stored in `day{day}/answers.toml`
+///
+/// The ledger is what stops us from sending an answer we already know is
+/// wrong: values that were rejected before, and guesses outside the bounds
+/// given by earlier "too high" / "too low" verdicts, are refused locally.
+#[derive(Debug, Default)]
+pub struct Ledger {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+/// A single submitted answer and what adventofcode.com said about it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: i32,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// seconds since the unix epoch
+    pub submitted_at: u64,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct LedgerFile {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Loads the ledger for the day of `puzzle`, or an empty one if nothing
+    /// was submitted yet
+    pub fn load(puzzle: impl Into<Puzzle>) -> Result<Self, AocError> {
+        Self::load_from(puzzle.into().ledger_path())
+    }
+
+    pub(crate) fn load_from(path: PathBuf) -> Result<Self, AocError> {
+        if !path.exists() {
+            return Ok(Self {
+                path,
+                submissions: vec![],
+            });
+        }
+
+        let content = read_to_string(&path).map_err(|e| AocError::io(&path, e))?;
+        let file: LedgerFile = toml::from_str(&content)
+            .map_err(|e| AocError::Ledger(format!("{}: {}", path.display(), e)))?;
+
+        Ok(Self {
+            path,
+            submissions: file.submissions,
+        })
+    }
+
+    /// All submissions for one part of a puzzle, oldest first
+    pub fn submissions(&self, puzzle: Puzzle, part: Part) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| s.year == puzzle.year && s.part == part.level())
+    }
+
+    /// Refuses answers that can not be right based on earlier verdicts
+    pub fn check(&self, puzzle: Puzzle, part: Part, answer: &str) -> Result<(), AocError> {
+        let reject = |reason: String| {
+            Err(AocError::AnswerRejected {
+                answer: answer.to_string(),
+                reason,
+            })
+        };
+
+        for submission in self.submissions(puzzle, part) {
+            match submission.verdict {
+                Verdict::Correct if submission.answer == answer => {
+                    return reject(format!("{} of {} was already solved with it", part, puzzle))
+                }
+                Verdict::Correct => {
+                    return reject(format!(
+                        "{} of {} was already solved with {}",
+                        part, puzzle, submission.answer
+                    ))
+                }
+                Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow
+                    if submission.answer == answer =>
+                {
+                    return reject(format!(
+                        "it was already submitted and was {}",
+                        submission.verdict
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let (lowest_too_high, highest_too_low) = self.bounds(puzzle, part);
+
+        if let Some(bound) = lowest_too_high.filter(|&bound| value >= bound) {
+            return reject(format!("{} was already too high", bound));
+        }
+        if let Some(bound) = highest_too_low.filter(|&bound| value <= bound) {
+            return reject(format!("{} was already too low", bound));
+        }
+        Ok(())
+    }
+
+    /// The lowest answer that was too high and the highest that was too low
+    fn bounds(&self, puzzle: Puzzle, part: Part) -> (Option<i128>, Option<i128>) {
+        let numeric = |verdict: Verdict| {
+            self.submissions(puzzle, part)
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        (
+            numeric(Verdict::TooHigh).min(),
+            numeric(Verdict::TooLow).max(),
+        )
+    }
+
+    /// Records a submission and saves the ledger
+    pub fn record(
+        &mut self,
+        puzzle: Puzzle,
+        part: Part,
+        answer: &str,
+        verdict: Verdict,
+    ) -> Result<(), AocError> {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        self.submissions.push(Submission {
+            year: puzzle.year,
+            part: part.level(),
+            answer: answer.to_string(),
+            verdict,
+            submitted_at,
+        });
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), AocError> {
+        let file = LedgerFile {
+            submissions: self.submissions.clone(),
+        };
+        let content = toml::to_string(&file).map_err(|e| AocError::Ledger(e.to_string()))?;
+
+        if let Some(dir) = self.path.parent() {
+            create_dir_all(dir).map_err(|e| AocError::io(dir, e))?;
+        }
+        write(&self.path, content).map_err(|e| AocError::io(&self.path, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tempfile::tempdir;
+
+    use crate::{ledger::Ledger, AocError, Part, Puzzle, Verdict};
+
+    const PUZZLE: Puzzle = Puzzle { year: 2022, day: 1 };
+
+    #[test]
+    fn can_record_and_reload_submissions() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("day1").join("answers.toml");
+
+        let mut ledger = Ledger::load_from(path.clone()).unwrap();
+        ledger
+            .record(PUZZLE, Part::One, "100", Verdict::TooLow)
+            .unwrap();
+        ledger
+            .record(
+                PUZZLE,
+                Part::One,
+                "500",
+                Verdict::RateLimited(Duration::from_secs(30)),
+            )
+            .unwrap();
+
+        let ledger = Ledger::load_from(path).unwrap();
+        let verdicts = ledger
+            .submissions(PUZZLE, Part::One)
+            .map(|s| s.verdict)
+            .collect::<Vec<_>>();
+
+        assert!(
+            verdicts
+                == vec![
+                    Verdict::TooLow,
+                    Verdict::RateLimited(Duration::from_secs(30))
+                ]
+        );
+        assert!(ledger.submissions(PUZZLE, Part::Two).count() == 0);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let dir = tempdir().unwrap();
+        let mut ledger = Ledger::load_from(dir.path().join("answers.toml")).unwrap();
+        ledger
+            .record(PUZZLE, Part::Two, "abc", Verdict::Wrong)
+            .unwrap();
+
+        let result = ledger.check(PUZZLE, Part::Two, "abc");
+
+        assert!(matches!(result, Err(AocError::AnswerRejected { .. })));
+        assert!(ledger.check(PUZZLE, Part::One, "abc").is_ok());
+        assert!(ledger.check(PUZZLE, Part::Two, "abd").is_ok());
+    }
+
+    #[test]
+    fn refuses_answers_outside_known_bounds() {
+        let dir = tempdir().unwrap();
+        let mut ledger = Ledger::load_from(dir.path().join("answers.toml")).unwrap();
+        ledger
+            .record(PUZZLE, Part::One, "1000", Verdict::TooHigh)
+            .unwrap();
+        ledger
+            .record(PUZZLE, Part::One, "400", Verdict::TooLow)
+            .unwrap();
+
+        assert!(ledger.check(PUZZLE, Part::One, "1200").is_err());
+        assert!(ledger.check(PUZZLE, Part::One, "1000").is_err());
+        assert!(ledger.check(PUZZLE, Part::One, "399").is_err());
+        assert!(ledger.check(PUZZLE, Part::One, "999").is_ok());
+    }
+
+    #[test]
+    fn refuses_answers_once_solved() {
+        let dir = tempdir().unwrap();
+        let mut ledger = Ledger::load_from(dir.path().join("answers.toml")).unwrap();
+        ledger
+            .record(PUZZLE, Part::One, "42", Verdict::Correct)
+            .unwrap();
+
+        assert!(ledger.check(PUZZLE, Part::One, "42").is_err());
+        assert!(ledger.check(PUZZLE, Part::One, "43").is_err());
+        assert!(ledger.check(Puzzle::new(2021, 1), Part::One, "43").is_ok());
+    }
+}
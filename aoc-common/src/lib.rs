@@ -1,24 +1,60 @@
-use std::{
-    fs::{read_to_string, File},
-    path::Path,
-};
+mod bench;
+mod cache;
+mod client;
+mod crypt;
+mod description;
+mod error;
+mod example;
+mod geom;
+mod grid;
+mod html;
+mod input;
+mod known;
+mod leaderboard;
+mod ledger;
+pub mod parse;
+mod paths;
+mod puzzle;
+mod search;
+mod session;
+mod solution;
+mod submit;
+mod throttle;
 
-use std::io::Write;
+use std::{fmt::Display, fs::read_to_string, path::Path};
 
-use reqwest::{
-    blocking::Client,
-    header::{HeaderMap, HeaderValue, COOKIE, USER_AGENT},
-    redirect::Policy,
-};
+use client::AocClient;
+use crypt::CacheKey;
 
-const YEAR: i32 = 2022;
+pub use bench::{bench, markdown_table, offline_input, Bench, BenchInput, Timing};
+pub use cache::{encrypt_cached_input, refresh_input, Corruption};
+pub use crypt::create_cache_key;
+pub use description::{fetch_description, Description};
+pub use error::AocError;
+pub use example::{examples, read_example_input, write_example_input, Example};
+pub use geom::{Direction, Point};
+pub use grid::Grid;
+pub use input::InputSource;
+pub use known::{Case, Check, Expected, KnownAnswers, Outcome};
+pub use leaderboard::{fetch_leaderboard, DayStars, Leaderboard, Member, Star};
+pub use ledger::{Ledger, Submission};
+pub use puzzle::{Part, Puzzle, DEFAULT_YEAR};
+pub use search::{astar, bfs, dijkstra, Search};
+pub use session::find_session_cookie;
+pub use solution::{print_answers, run, solve, Answer, Day, Registry, Solution, Solved};
+pub use submit::{submit_answer, Verdict};
 
 /// Fetches the puzzle input from adventofcode.com
 ///
-/// This will try and save the input as a file in inputs/day_{day}.txt
-/// subsequent calls will pull from this file.  If no file is found
-/// it will use the client.  This requires the .session.cookie file to be
-/// created, along with a valid cookie value it can read.
+/// This will try and save the input as a file in
+/// day{day}/inputs/{year}/day_{day}.txt under the workspace root (or in
+/// `$AOC_INPUT_DIR/{year}/day_{day}.txt` when set), subsequent calls will
+/// pull from this file.  A cached input that turns out to be corrupt (see
+/// [`Corruption`]) is downloaded again.  If no file is found it will use the client.  This requires
+/// a session cookie, see [`find_session_cookie`] for where it is looked up.
+///
+/// The first parameter is either a day (of [`DEFAULT_YEAR`]) or a [`Puzzle`]
+/// for any other year.
 ///
 /// The second parameter is an transform function defined as
 ///     `Fn(String) -> Vec<T>`
@@ -34,24 +70,111 @@ const YEAR: i32 = 2022;
 /// };
 ///
 /// let input = fetch_with_transform(1, transform);
+/// let last_year = fetch_with_transform(Puzzle::new(2021, 1), transform);
 ///
 /// assert!(input.len() == 10);
 /// ```
 ///
-pub fn fetch_with_transform<F, T>(day: i32, transform: F) -> T
+pub fn fetch_with_transform<F, T>(puzzle: impl Into<Puzzle>, transform: F) -> T
 where
     F: Fn(String) -> T,
 {
-    if Path::new(&format!("day{}/inputs/day_{}.txt", day, day)).exists() {
-        fetch_from_file_with_transform(day, transform)
-    } else {
-        match fetch_from_url_with_transform(day, transform) {
-            Ok(content) => content,
-            Err(e) => panic!("there was an error fetching content: {}", e),
+    match try_fetch_with_transform(puzzle, transform) {
+        Ok(content) => content,
+        Err(e) => panic!("there was an error fetching content: {}", e),
+    }
+}
+
+/// Same as [`fetch_with_transform`], but reports problems as an [`AocError`]
+/// instead of panicking.  With `AOC_OFFLINE` set this only reads the cache,
+/// like [`try_fetch_cached_with_transform`].
+pub fn try_fetch_with_transform<F, T>(
+    puzzle: impl Into<Puzzle>,
+    transform: F,
+) -> Result<T, AocError>
+where
+    F: Fn(String) -> T,
+{
+    let puzzle = puzzle.into();
+    if client::offline() {
+        return try_fetch_cached_with_transform(puzzle, transform);
+    }
+    if puzzle.cached_input_path().is_none() {
+        let client = AocClient::new()?;
+        let key = CacheKey::load()?;
+        return fetch_from_url_with_transform(
+            &client,
+            key.as_ref(),
+            puzzle,
+            &puzzle.input_path(),
+            transform,
+        );
+    }
+    match fetch_from_file_with_transform(puzzle, &transform) {
+        Err(AocError::CorruptInput { path, corruption }) => {
+            eprintln!("{}: {}, downloading it again", path.display(), corruption);
+            refresh_input(puzzle).map(transform)
         }
+        result => result,
+    }
+}
+
+/// Only reads the cached input, failing with [`AocError::NotCached`]
+/// instead of downloading it
+pub fn try_fetch_cached_with_transform<F, T>(
+    puzzle: impl Into<Puzzle>,
+    transform: F,
+) -> Result<T, AocError>
+where
+    F: Fn(String) -> T,
+{
+    let puzzle = puzzle.into();
+    match puzzle.cached_input_path() {
+        Some(_) => fetch_from_file_with_transform(puzzle, transform),
+        None => Err(AocError::NotCached(puzzle)),
     }
 }
 
+/// Fetches the puzzle input and runs a fallible `transform` over every line.
+///
+/// The first line that fails to transform is reported as an
+/// [`AocError::Transform`] carrying the puzzle and the (1 based) line number.
+///
+/// # Example
+/// ```ignore
+/// # use crate::aoc_common::try_fetch_lines_with_transform;
+/// let instructions = try_fetch_lines_with_transform(10, |s| s.parse::<Inst>())?;
+/// ```
+pub fn try_fetch_lines_with_transform<F, T, E>(
+    puzzle: impl Into<Puzzle>,
+    transform: F,
+) -> Result<Vec<T>, AocError>
+where
+    F: Fn(String) -> Result<T, E>,
+    E: Display,
+{
+    let puzzle = puzzle.into();
+    let content = try_fetch_with_transform(puzzle, |s| s)?;
+    transform_lines(puzzle, &content, transform)
+}
+
+/// Fetches the puzzle input and runs a fallible `transform` over every block
+/// of lines separated by a blank line.
+///
+/// Errors point at the line the offending block starts on.
+pub fn try_fetch_blocks_with_transform<F, T, E>(
+    puzzle: impl Into<Puzzle>,
+    transform: F,
+) -> Result<Vec<T>, AocError>
+where
+    F: Fn(String) -> Result<T, E>,
+    E: Display,
+{
+    let puzzle = puzzle.into();
+    let content = try_fetch_with_transform(puzzle, |s| s)?;
+    transform_blocks(puzzle, &content, transform)
+}
+
 /// Reads an input file from `input/test_input.txt` that contains the test data.
 /// For use in unit tests (helper function)
 pub fn get_test_input<F, T>(filename: &str, transform: F) -> T
@@ -62,73 +185,94 @@ where
     transform(content)
 }
 
-fn fetch_from_file_with_transform<F, T>(day: i32, transform: F) -> T
+/// Runs a fallible `transform` over every line of `content`, reporting the
+/// first failure like [`try_fetch_lines_with_transform`] does.  Useful in a
+/// [`Solution::parse`].
+pub fn transform_lines<F, T, E>(
+    puzzle: Puzzle,
+    content: &str,
+    transform: F,
+) -> Result<Vec<T>, AocError>
 where
-    F: Fn(String) -> T,
+    F: Fn(String) -> Result<T, E>,
+    E: Display,
 {
-    let filename = format!("day{}/inputs/day_{}.txt", day, day);
-    let Ok(content) = read_to_string(filename) else {
-        panic!("could not read input file");
-    };
-    transform(content)
+    content
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            transform(line.to_string()).map_err(|e| AocError::Transform {
+                puzzle,
+                line: idx + 1,
+                message: e.to_string(),
+            })
+        })
+        .collect()
 }
 
-fn fetch_from_url_with_transform<F, T>(day: i32, transform: F) -> Result<T, String>
+/// Runs a fallible `transform` over every blank line separated block of
+/// `content`, like [`try_fetch_blocks_with_transform`] does
+pub fn transform_blocks<F, T, E>(
+    puzzle: Puzzle,
+    content: &str,
+    transform: F,
+) -> Result<Vec<T>, AocError>
 where
-    F: Fn(String) -> T,
+    F: Fn(String) -> Result<T, E>,
+    E: Display,
 {
-    let url = format!("https://adventofcode.com/{}/day/{}/input", YEAR, day);
-    let resp = build_client()?
-        .get(url)
-        .send()
-        .and_then(|resp| resp.error_for_status())
-        .and_then(|resp| resp.text());
-
-    match resp {
-        Ok(text) => {
-            write_to_file(day, &text);
-            Ok(transform(text))
-        }
-        Err(e) => Err(e.to_string()),
-    }
-}
+    let mut line = 1;
+    let mut blocks = vec![];
 
-fn write_to_file(day: i32, text: &str) {
-    let path = format!("day{}/inputs/day_{}.txt", day, day);
-    if !Path::new(&path).exists() {
-        match File::create(path) {
-            Ok(mut output) => write!(output, "{}", text.trim()).expect("Error writing to file"),
-            Err(e) => eprintln!("Error creating input file: {}", e),
-        }
+    for block in content.trim_end().split("\n\n") {
+        let value = transform(block.to_string()).map_err(|e| AocError::Transform {
+            puzzle,
+            line,
+            message: e.to_string(),
+        })?;
+        blocks.push(value);
+        line += block.lines().count() + 1;
     }
+    Ok(blocks)
 }
 
-fn build_client() -> Result<Client, String> {
-    let session_cookie = read_to_string(".session.cookie").expect("no session cookie found!");
-
-    let cookie_header = HeaderValue::from_str(&format!("session={}", session_cookie.trim()))
-        .map_err(|e| format!("Invalid session cookie: {}", e))?;
-
-    let user_agent_header =
-        HeaderValue::from_str("github.com/mpalmer16/aoc-2022-rs by mpalmer1661@gmail.com")
-            .map_err(|e| format!("Invalid user agent: {}", e))?;
-
-    let mut headers = HeaderMap::new();
+fn fetch_from_file_with_transform<F, T>(puzzle: Puzzle, transform: F) -> Result<T, AocError>
+where
+    F: Fn(String) -> T,
+{
+    let filename = puzzle
+        .cached_input_path()
+        .unwrap_or_else(|| puzzle.input_path());
+    let content = cache::read_checked(&filename, CacheKey::load()?.as_ref())?;
+    Ok(transform(content))
+}
 
-    headers.insert(COOKIE, cookie_header);
-    headers.insert(USER_AGENT, user_agent_header);
-    Client::builder()
-        .default_headers(headers)
-        .redirect(Policy::none())
-        .build()
-        .map_err(|e| e.to_string())
+fn fetch_from_url_with_transform<F, T>(
+    client: &AocClient,
+    key: Option<&CacheKey>,
+    puzzle: Puzzle,
+    path: &Path,
+    transform: F,
+) -> Result<T, AocError>
+where
+    F: Fn(String) -> T,
+{
+    let text = cache::download(client, key, puzzle, path)?;
+    Ok(transform(text))
 }
 
 #[cfg(test)]
 mod tests {
+    use std::fs::read_to_string;
+
+    use mockito::Server;
+    use tempfile::tempdir;
+
     use crate::{
-        fetch_from_file_with_transform, fetch_from_url_with_transform, fetch_with_transform,
-        get_test_input,
+        client::AocClient, fetch_from_file_with_transform, fetch_from_url_with_transform,
+        get_test_input, transform_blocks, transform_lines, try_fetch_cached_with_transform,
+        AocError, Puzzle,
     };
 
     #[test]
@@ -140,37 +284,81 @@ mod tests {
                 .map(|s| s.to_string())
                 .collect::<Vec<String>>()
         };
-        let input = fetch_from_file_with_transform(1, transform);
+        let input = fetch_from_file_with_transform(1.into(), transform).unwrap();
 
         assert!(input.len() == 10);
     }
 
     #[test]
-    #[ignore]
     fn can_fetch_input_from_url() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/2022/day/1/input")
+            .with_body("1000\n2000\n\n3000\n")
+            .create();
+        let dir = tempdir().unwrap();
+        let client = AocClient::with_base_url(&server.url());
         let transform = |s: String| {
             s.trim()
                 .split('\n')
                 .map(|s| s.to_string())
                 .collect::<Vec<String>>()
         };
-        let input = fetch_from_url_with_transform(1, transform).unwrap();
+        let input = fetch_from_url_with_transform(
+            &client,
+            None,
+            1.into(),
+            &dir.path().join("day_1.txt"),
+            transform,
+        )
+        .unwrap();
 
-        assert!(input.len() == 2253);
+        mock.assert();
+        assert!(input.len() == 4);
     }
 
     #[test]
-    #[ignore]
     fn can_fetch_and_save_to_file() {
-        let transform = |s: String| {
-            s.trim()
-                .split('\n')
-                .map(|s| s.to_string())
-                .collect::<Vec<String>>()
-        };
-        let input = fetch_with_transform(2, transform);
+        let mut server = Server::new();
+        server
+            .mock("GET", "/2022/day/2/input")
+            .with_body("A Y\nB X\nC Z\n")
+            .create();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("2022").join("day_2.txt");
+        let client = AocClient::with_base_url(&server.url());
 
-        assert!(input.len() == 1000);
+        let input = fetch_from_url_with_transform(&client, None, 2.into(), &path, |s| s).unwrap();
+
+        assert!(input == "A Y\nB X\nC Z\n");
+        assert!(read_to_string(&path).unwrap() == input);
+        assert!(path.with_extension("sha256").exists());
+    }
+
+    #[test]
+    fn fail_on_server_errors() {
+        let mut server = Server::new();
+        server
+            .mock("GET", "/2022/day/3/input")
+            .with_status(500)
+            .create();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("day_3.txt");
+        let client = AocClient::with_base_url(&server.url());
+
+        let result = fetch_from_url_with_transform(&client, None, 3.into(), &path, |s| s);
+
+        assert!(matches!(result, Err(AocError::HttpStatus(500))));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn reports_uncached_input_when_fetching_from_the_cache_only() {
+        let result = try_fetch_cached_with_transform(Puzzle::new(2015, 25), |s| s);
+
+        assert!(
+            matches!(result, Err(AocError::NotCached(puzzle)) if puzzle == Puzzle::new(2015, 25))
+        );
     }
 
     #[test]
@@ -185,4 +373,50 @@ mod tests {
 
         assert!(input.len() == 5);
     }
+
+    #[test]
+    fn reports_missing_input_file() {
+        let result = fetch_from_file_with_transform(99.into(), |s| s);
+
+        assert!(matches!(result, Err(AocError::Io { .. })));
+    }
+
+    #[test]
+    fn can_display_errors() {
+        assert!(
+            AocError::HttpStatus(404).to_string() == "adventofcode.com responded with status 404"
+        );
+        let missing = AocError::MissingCookie {
+            tried: vec!["$AOC_SESSION".to_string(), ".session.cookie".to_string()],
+        };
+        assert!(
+            missing.to_string() == "no session cookie found, tried: $AOC_SESSION, .session.cookie"
+        );
+    }
+
+    #[test]
+    fn reports_the_line_a_transform_failed_on() {
+        let result = transform_lines(4.into(), "1\n2\nthree\n4", |s| s.parse::<i32>());
+
+        match result {
+            Err(AocError::Transform { puzzle, line, .. }) => {
+                assert!(puzzle == Puzzle::new(2022, 4) && line == 3)
+            }
+            _ => panic!("expected a transform error"),
+        }
+    }
+
+    #[test]
+    fn reports_the_line_a_failing_block_starts_on() {
+        let transform = |s: String| s.lines().map(|l| l.parse::<i32>()).sum::<Result<i32, _>>();
+
+        let sums = transform_blocks(1.into(), "1\n2\n\n3\n\n4\n5", transform).unwrap();
+        assert!(sums == vec![3, 3, 9]);
+
+        let result = transform_blocks(1.into(), "1\n2\n\n3\n\n4\nfive", transform);
+        match result {
+            Err(AocError::Transform { line, .. }) => assert!(line == 6),
+            _ => panic!("expected a transform error"),
+        }
+    }
 }
@@ -0,0 +1,285 @@
+//! [nom] combinators for the shapes puzzle inputs usually come in, run them
+//! over a whole input with [`parse_all`]
+
+use nom::{
+    branch::alt,
+    bytes::complete::{is_not, tag, take_till1},
+    character::complete::{char, line_ending, space0, space1},
+    combinator::all_consuming,
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::{pair, preceded, tuple},
+    Finish, IResult, Parser,
+};
+
+use crate::{AocError, Grid, Puzzle};
+
+/// Runs `parser` over all of `input` (trailing whitespace aside), failures
+/// are reported with the line and column they happened at
+///
+/// # Example
+/// ```ignore
+/// let elves = parse_all(Self::PUZZLE, &input, blocks(lines(signed)))?;
+/// ```
+pub fn parse_all<'a, O, F>(puzzle: Puzzle, input: &'a str, parser: F) -> Result<O, AocError>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    let input = input.trim_end();
+
+    all_consuming(parser)(input)
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|e| {
+            let (line, column) = position(input, e.input);
+            AocError::Parse {
+                puzzle,
+                line,
+                column,
+                message: describe(&e),
+            }
+        })
+}
+
+/// Blocks separated by a blank line, like the elves of 2022 day 1
+pub fn blocks<'a, O, F>(block: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(pair(line_ending, line_ending), block)
+}
+
+/// One `line` per line, stops before a blank line
+pub fn lines<'a, O, F>(line: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(line_ending, line)
+}
+
+/// An integer with an optional sign
+pub fn signed(input: &str) -> IResult<&str, i64> {
+    nom::character::complete::i64(input)
+}
+
+/// Integers separated by commas and/or spaces, like `79, 98` or `-1 2 -3`
+pub fn integers(input: &str) -> IResult<&str, Vec<i64>> {
+    separated_list1(alt((tag(", "), tag(","), space1)), signed)(input)
+}
+
+/// A `key: value` line (leading indentation allowed), giving the key and
+/// the value
+pub fn key_value<'a, O, F>(value: F) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, O)>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    pair(
+        preceded(space0, take_till1(|c| c == ':' || c == '\n')),
+        preceded(pair(char(':'), space0), value),
+    )
+}
+
+/// The value of a `key: value` line with the given `key`, like
+/// `field("Starting items", integers)`
+pub fn field<'a, O, F>(key: &'a str, value: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    preceded(tuple((space0, tag(key), char(':'), space0)), value)
+}
+
+/// A grid of single digits, like the trees of 2022 day 8
+pub fn digit_grid(input: &str) -> IResult<&str, Grid<u32>> {
+    char_grid(|c| c.to_digit(10))(input)
+}
+
+/// A grid with a cell for every character `cell` accepts, all rows have to
+/// be the same length
+pub fn char_grid<'a, T, F>(cell: F) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>>
+where
+    F: Fn(char) -> Option<T>,
+{
+    move |input| {
+        let (rest, raw) = separated_list1(line_ending, is_not("\r\n"))(input)?;
+        let mut rows = vec![];
+
+        for line in raw {
+            // errors have to point into what is left of the whole input, not
+            // just the row, for `parse_all` to find their line and column
+            let start = line.as_ptr() as usize - input.as_ptr() as usize;
+            let row = line
+                .char_indices()
+                .map(|(idx, c)| {
+                    let rest = &input[start + idx..];
+                    cell(c).ok_or(nom::Err::Error(Error::new(rest, ErrorKind::MapOpt)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if rows
+                .first()
+                .is_some_and(|first: &Vec<T>| first.len() != row.len())
+            {
+                let rest = &input[start..];
+                return Err(nom::Err::Error(Error::new(rest, ErrorKind::LengthValue)));
+            }
+            rows.push(row);
+        }
+        Ok((rest, Grid::from_rows(rows).expect("rows are checked above")))
+    }
+}
+
+/// The (1 based) line and column `rest` starts at in `input`
+fn position(input: &str, rest: &str) -> (usize, usize) {
+    let before = &input[..input.len() - rest.len()];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+fn describe(e: &Error<&str>) -> String {
+    let expected = match e.code {
+        ErrorKind::Eof => "the end of the input".to_string(),
+        ErrorKind::LengthValue => "a row as long as the first one".to_string(),
+        ErrorKind::MapOpt => "a grid cell".to_string(),
+        kind => kind.description().to_lowercase(),
+    };
+    match e.input.lines().next().filter(|rest| !rest.is_empty()) {
+        Some(found) => format!("expected {}, found {:?}", expected, found),
+        None => format!("expected {}, found the end of the input", expected),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{char, line_ending},
+        sequence::{delimited, preceded, tuple},
+    };
+
+    use crate::{
+        parse::{
+            blocks, char_grid, digit_grid, field, integers, key_value, lines, parse_all, signed,
+        },
+        AocError, Puzzle,
+    };
+
+    const PUZZLE: Puzzle = Puzzle::new(2022, 11);
+
+    #[test]
+    fn can_parse_blocks_of_lines() {
+        let elves = parse_all(
+            PUZZLE,
+            "1000\n2000\n\n-4000\n\n5000\n6000\n",
+            blocks(lines(signed)),
+        );
+
+        assert!(elves.unwrap() == vec![vec![1000, 2000], vec![-4000], vec![5000, 6000]]);
+    }
+
+    #[test]
+    fn can_parse_integer_lists() {
+        assert!(parse_all(PUZZLE, "79, 98", integers).unwrap() == [79, 98]);
+        assert!(parse_all(PUZZLE, "-1 2 -3", integers).unwrap() == [-1, 2, -3]);
+        assert!(parse_all(PUZZLE, "1,2", integers).unwrap() == [1, 2]);
+    }
+
+    #[test]
+    fn can_parse_key_value_lines() {
+        let monkey = "Monkey 0:\n  Starting items: 79, 98\n  Test: divisible by 23";
+        let parser = tuple((
+            delimited(tag("Monkey "), signed, char(':')),
+            preceded(line_ending, field("Starting items", integers)),
+            preceded(
+                line_ending,
+                key_value(preceded(tag("divisible by "), signed)),
+            ),
+        ));
+
+        let (id, items, test) = parse_all(PUZZLE, monkey, parser).unwrap();
+
+        assert!(id == 0 && items == [79, 98]);
+        assert!(test == ("Test", 23));
+    }
+
+    #[test]
+    fn can_parse_grids() {
+        let trees = parse_all(PUZZLE, "303\n255\n", digit_grid).unwrap();
+        let walls = parse_all(PUZZLE, "#.\n.#", char_grid(|c| Some(c == '#'))).unwrap();
+
+        assert!(trees.height() == 2 && trees[(2, 1)] == 5);
+        assert!(walls[(0, 0)] && !walls[(1, 0)]);
+    }
+
+    #[test]
+    fn reports_line_and_column() {
+        let bad_item = parse_all(PUZZLE, "1, 2\n3, x4", lines(integers));
+        let ragged = parse_all(PUZZLE, "123\n45\n", digit_grid);
+        let bad_digit = parse_all(PUZZLE, "123\n4x6\n", digit_grid);
+
+        assert!(matches!(
+            bad_item,
+            Err(AocError::Parse { line: 2, column: 2, message, .. })
+                if message == "expected the end of the input, found \", x4\""
+        ));
+        assert!(matches!(
+            ragged,
+            Err(AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            bad_digit,
+            Err(AocError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn reports_line_and_column_before_the_last_line() {
+        let bad_digit = parse_all(PUZZLE, "1x3\n456\n789", digit_grid);
+        let ragged = parse_all(PUZZLE, "12\n4\n789", digit_grid);
+        let multibyte = parse_all(
+            PUZZLE,
+            "aé1\nbcd",
+            char_grid(|c| c.is_alphabetic().then_some(c)),
+        );
+
+        assert!(matches!(
+            bad_digit,
+            Err(AocError::Parse { line: 1, column: 2, message, .. })
+                if message == "expected a grid cell, found \"x3\""
+        ));
+        assert!(matches!(
+            ragged,
+            Err(AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            multibyte,
+            Err(AocError::Parse {
+                line: 1,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_all(PUZZLE, "x\né", digit_grid),
+            Err(AocError::Parse {
+                line: 1,
+                column: 1,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,25 @@
+use std::path::{Path, PathBuf};
+
+const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// The root of the workspace this crate was built in
+pub(crate) fn workspace_root() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf()
+}
+
+/// The user config directory, `$XDG_CONFIG_HOME` or `~/.config`
+pub(crate) fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+}
+
+/// Where inputs are cached when `AOC_INPUT_DIR` is set, instead of the
+/// per day crate directories in the workspace
+pub(crate) fn input_dir_override() -> Option<PathBuf> {
+    std::env::var_os(INPUT_DIR_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
@@ -0,0 +1,215 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use crate::paths::{input_dir_override, workspace_root};
+
+/// The event year used when a fetch function is only given a day
+pub const DEFAULT_YEAR: i32 = 2022;
+
+/// Identifies a single puzzle, e.g. `Puzzle::new(2022, 5)`
+///
+/// Every fetch function accepts anything that converts into a `Puzzle`,
+/// so passing a plain day number keeps working and means [`DEFAULT_YEAR`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Puzzle {
+    pub year: i32,
+    pub day: i32,
+}
+
+impl Puzzle {
+    pub const fn new(year: i32, day: i32) -> Self {
+        Self { year, day }
+    }
+
+    pub(crate) fn url(&self, base_url: &str) -> String {
+        format!("{}/{}/day/{}", base_url, self.year, self.day)
+    }
+
+    pub(crate) fn input_url(&self, base_url: &str) -> String {
+        format!("{}/input", self.url(base_url))
+    }
+
+    pub(crate) fn answer_url(&self, base_url: &str) -> String {
+        format!("{}/answer", self.url(base_url))
+    }
+
+    /// The `day{day}` crate directory in the workspace
+    pub(crate) fn crate_dir(&self) -> PathBuf {
+        workspace_root().join(format!("day{}", self.day))
+    }
+
+    /// The example input used by the day's tests: `day{day}/inputs/test_input.txt`
+    pub(crate) fn example_path(&self) -> PathBuf {
+        self.crate_dir().join("inputs").join("test_input.txt")
+    }
+
+    /// Where submitted answers are recorded: `day{day}/answers.toml`
+    pub(crate) fn ledger_path(&self) -> PathBuf {
+        self.crate_dir().join("answers.toml")
+    }
+
+    /// The answers the day is known to produce: `day{day}/known_answers.toml`
+    pub(crate) fn known_answers_path(&self) -> PathBuf {
+        self.crate_dir().join("known_answers.toml")
+    }
+
+    /// Where the downloaded input is cached.
+    ///
+    /// This is `day{day}/inputs/{year}/day_{day}.txt` under the workspace
+    /// root, no matter which directory the binary was started from, or
+    /// `$AOC_INPUT_DIR/{year}/day_{day}.txt` when that is set.
+    pub(crate) fn input_path(&self) -> PathBuf {
+        self.input_path_in(input_dir_override(), &workspace_root())
+    }
+
+    fn input_path_in(&self, input_dir: Option<PathBuf>, root: &Path) -> PathBuf {
+        match input_dir {
+            Some(dir) => dir
+                .join(self.year.to_string())
+                .join(format!("day_{}.txt", self.day)),
+            None => root
+                .join(format!("day{}", self.day))
+                .join("inputs")
+                .join(self.year.to_string())
+                .join(format!("day_{}.txt", self.day)),
+        }
+    }
+
+    /// Where the Markdown puzzle description is saved, next to the input
+    pub(crate) fn description_path(&self) -> PathBuf {
+        self.input_path().with_extension("md")
+    }
+
+    /// Inputs cached before the year was part of the layout, only valid for
+    /// [`DEFAULT_YEAR`] without an `AOC_INPUT_DIR` override
+    pub(crate) fn legacy_input_path(&self) -> Option<PathBuf> {
+        self.legacy_input_path_in(input_dir_override(), &workspace_root())
+    }
+
+    fn legacy_input_path_in(&self, input_dir: Option<PathBuf>, root: &Path) -> Option<PathBuf> {
+        (self.year == DEFAULT_YEAR && input_dir.is_none()).then(|| {
+            root.join(format!("day{}", self.day))
+                .join("inputs")
+                .join(format!("day_{}.txt", self.day))
+        })
+    }
+
+    /// The cached input file to read from, if one exists
+    pub(crate) fn cached_input_path(&self) -> Option<PathBuf> {
+        std::iter::once(self.input_path())
+            .chain(self.legacy_input_path())
+            .find(|path| path.exists())
+    }
+}
+
+/// One of the two halves of a puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// The `level` adventofcode.com uses for this part
+    pub fn level(&self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(level: u8) -> Result<Self, Self::Error> {
+        match level {
+            1 => Ok(Self::One),
+            2 => Ok(Self::Two),
+            _ => Err(format!("there is no part {}", level)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "part {}", self.level())
+    }
+}
+
+impl From<i32> for Puzzle {
+    fn from(day: i32) -> Self {
+        Self::new(DEFAULT_YEAR, day)
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::{
+        client::BASE_URL,
+        puzzle::{Part, Puzzle, DEFAULT_YEAR},
+    };
+
+    #[test]
+    fn day_defaults_to_the_default_year() {
+        let puzzle: Puzzle = 5.into();
+
+        assert!(puzzle == Puzzle::new(DEFAULT_YEAR, 5));
+    }
+
+    #[test]
+    fn years_do_not_share_a_cache_file() {
+        let root = Path::new("/workspace");
+        let this_year = Puzzle::new(2022, 1);
+        let last_year = Puzzle::new(2021, 1);
+
+        assert!(this_year.input_path_in(None, root) != last_year.input_path_in(None, root));
+        assert!(this_year.input_path_in(None, root) == root.join("day1/inputs/2022/day_1.txt"));
+        assert!(last_year.legacy_input_path_in(None, root).is_none());
+    }
+
+    #[test]
+    fn input_path_does_not_depend_on_the_current_dir() {
+        let puzzle = Puzzle::new(2022, 3);
+
+        assert!(puzzle.input_path().is_absolute());
+        assert!(puzzle.input_path().ends_with("day3/inputs/2022/day_3.txt"));
+    }
+
+    #[test]
+    fn input_dir_override_replaces_the_workspace_layout() {
+        let root = Path::new("/workspace");
+        let puzzle = Puzzle::new(2022, 3);
+        let input_dir = Some(PathBuf::from("/tmp/aoc"));
+
+        assert!(
+            puzzle.input_path_in(input_dir.clone(), root) == Path::new("/tmp/aoc/2022/day_3.txt")
+        );
+        assert!(puzzle.legacy_input_path_in(input_dir, root).is_none());
+    }
+
+    #[test]
+    fn can_build_input_url() {
+        let puzzle = Puzzle::new(2015, 25);
+
+        assert!(puzzle.input_url(BASE_URL) == "https://adventofcode.com/2015/day/25/input");
+        assert!(puzzle.answer_url(BASE_URL) == "https://adventofcode.com/2015/day/25/answer");
+    }
+
+    #[test]
+    fn can_convert_levels_to_parts() {
+        assert!(Part::try_from(2) == Ok(Part::Two));
+        assert!(Part::try_from(3).is_err());
+        assert!(Part::One.to_string() == "part 1");
+    }
+}
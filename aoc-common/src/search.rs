@@ -0,0 +1,286 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The outcome of a search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search<S, C> {
+    /// the cost of the cheapest path to a goal and the path itself, from
+    /// its start up to and including the goal, `None` if no goal is reachable
+    pub found: Option<(C, Vec<S>)>,
+    /// how many states were expanded before the search ended
+    pub visited: usize,
+}
+
+/// Breadth first search from every state in `starts` to the closest state
+/// `goal` accepts, the cost is the number of steps taken.
+///
+/// # Example
+/// ```ignore
+/// let search = bfs([start], |&pos| grid.neighbours(pos), |&pos| pos == end);
+/// let steps = search.found.map(|(steps, _)| steps);
+/// ```
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut states = States::default();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Some(idx) = states.insert(start, None, 0) {
+            queue.push_back(idx);
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(idx) = queue.pop_front() {
+        visited += 1;
+        let state = states.state[idx].clone();
+        if goal(&state) {
+            return states.found(idx, visited);
+        }
+
+        let steps = states.cost[idx] + 1;
+        for next in neighbours(&state) {
+            if let Some(next) = states.insert(next, Some(idx), steps) {
+                queue.push_back(next);
+            }
+        }
+    }
+    Search {
+        found: None,
+        visited,
+    }
+}
+
+/// Dijkstra's search from every state in `starts` to the cheapest state
+/// `goal` accepts, stepping from one state to the next costs what `cost`
+/// says it does
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    cost: impl FnMut(&S, &S) -> C,
+    goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = S>,
+{
+    astar(starts, neighbours, cost, |_| C::default(), goal)
+}
+
+/// A* search, like [`dijkstra`] but states that `heuristic` estimates to be
+/// closer to a goal are expanded first.  The heuristic must never
+/// overestimate the remaining cost, or the path found may not be the
+/// cheapest one.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut cost: impl FnMut(&S, &S) -> C,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = S>,
+{
+    let mut states = States::default();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(idx) = states.insert(start, None, C::default()) {
+            heap.push(Reverse((estimate, C::default(), idx)));
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(Reverse((_, so_far, idx))) = heap.pop() {
+        if so_far > states.cost[idx] {
+            // a cheaper way here was found after this one was queued
+            continue;
+        }
+        visited += 1;
+        let state = states.state[idx].clone();
+        if goal(&state) {
+            return states.found(idx, visited);
+        }
+
+        for next in neighbours(&state) {
+            let total = so_far + cost(&state, &next);
+            let estimate = total + heuristic(&next);
+            if let Some(next) = states.insert(next, Some(idx), total) {
+                heap.push(Reverse((estimate, total, next)));
+            }
+        }
+    }
+    Search {
+        found: None,
+        visited,
+    }
+}
+
+/// Every state seen so far with the cheapest known cost to reach it and
+/// the state it was reached from, states are referred to by index
+struct States<S, C> {
+    index: HashMap<S, usize>,
+    state: Vec<S>,
+    cost: Vec<C>,
+    parent: Vec<Option<usize>>,
+}
+
+impl<S, C> Default for States<S, C> {
+    fn default() -> Self {
+        Self {
+            index: HashMap::new(),
+            state: vec![],
+            cost: vec![],
+            parent: vec![],
+        }
+    }
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> States<S, C> {
+    /// Records reaching `state` from `parent` for `cost`, returning its
+    /// index if that is new or cheaper than before
+    fn insert(&mut self, state: S, parent: Option<usize>, cost: C) -> Option<usize> {
+        match self.index.entry(state) {
+            Entry::Occupied(entry) => {
+                let idx = *entry.get();
+                (cost < self.cost[idx]).then(|| {
+                    self.cost[idx] = cost;
+                    self.parent[idx] = parent;
+                    idx
+                })
+            }
+            Entry::Vacant(entry) => {
+                let idx = self.state.len();
+                self.state.push(entry.key().clone());
+                self.cost.push(cost);
+                self.parent.push(parent);
+                entry.insert(idx);
+                Some(idx)
+            }
+        }
+    }
+
+    fn found(&self, goal: usize, visited: usize) -> Search<S, C> {
+        let mut path = vec![];
+        let mut idx = Some(goal);
+        while let Some(current) = idx {
+            path.push(self.state[current].clone());
+            idx = self.parent[current];
+        }
+        path.reverse();
+
+        Search {
+            found: Some((self.cost[goal], path)),
+            visited,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        search::{astar, bfs, dijkstra},
+        Direction, Grid, Point, Puzzle,
+    };
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    fn maze() -> (Grid<char>, (usize, usize), (usize, usize)) {
+        let grid = Grid::parse(Puzzle::new(2022, 12), MAZE, Some).unwrap();
+        let start = grid.find(|&c| c == 'S').unwrap();
+        let end = grid.find(|&c| c == 'E').unwrap();
+        (grid, start, end)
+    }
+
+    #[test]
+    fn can_find_the_shortest_path() {
+        let (grid, start, end) = maze();
+        let open = |&pos: &(usize, usize)| {
+            grid.neighbours(pos)
+                .filter(|&next| grid[next] != '#')
+                .collect::<Vec<_>>()
+        };
+
+        let search = bfs([start], open, |&pos| pos == end);
+        let (steps, path) = search.found.unwrap();
+
+        assert!(steps == 15);
+        assert!(path.len() == 16 && path[0] == start && path[15] == end);
+        assert!(path
+            .windows(2)
+            .all(|w| Point::from(w[0]).manhattan(Point::from(w[1])) == 1));
+        assert!(search.visited > 0);
+    }
+
+    #[test]
+    fn can_start_from_many_states() {
+        let (grid, start, end) = maze();
+        let open = |&pos: &(usize, usize)| {
+            grid.neighbours(pos)
+                .filter(|&next| grid[next] != '#')
+                .collect::<Vec<_>>()
+        };
+
+        let search = bfs([start, (7, 0)], open, |&pos| pos == end);
+
+        assert!(search.found.unwrap() == (4, vec![(7, 0), (7, 1), (7, 2), (7, 3), (7, 4)]));
+        assert!(bfs([start], |_| vec![], |&pos| pos == end).found.is_none());
+    }
+
+    #[test]
+    fn can_weigh_steps() {
+        // going down costs 10, so the path only goes down where it has to
+        let (grid, start, _) = maze();
+        let open = |&pos: &(usize, usize)| {
+            grid.neighbours(pos)
+                .filter(|&next| grid[next] != '#')
+                .collect::<Vec<_>>()
+        };
+        let cost = |from: &(usize, usize), to: &(usize, usize)| if to.1 > from.1 { 10 } else { 1 };
+
+        let (total, path) = dijkstra([start], open, cost, |&pos| pos == (7, 1))
+            .found
+            .unwrap();
+
+        assert!(total == 39);
+        assert!(path.windows(2).filter(|w| w[1].1 > w[0].1).count() == 3);
+    }
+
+    #[test]
+    fn can_guide_the_search() {
+        let goal = Point::new(20, 20);
+        let open = |p: &Point| Direction::CARDINAL.map(|d| p.step(d));
+        let one = |_: &Point, _: &Point| 1;
+
+        let guided = astar(
+            [Point::ORIGIN],
+            open,
+            one,
+            |p| p.manhattan(goal),
+            |&p| p == goal,
+        );
+        let blind = dijkstra([Point::ORIGIN], open, one, |&p| p == goal);
+
+        assert!(guided.found.as_ref().unwrap().0 == 40);
+        assert!(blind.found.as_ref().unwrap().0 == 40);
+        assert!(guided.visited < blind.visited);
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    paths::{config_dir, workspace_root},
+    AocError,
+};
+
+const SESSION_ENV: &str = "AOC_SESSION";
+const COOKIE_FILE: &str = ".session.cookie";
+
+/// Finds the adventofcode.com session cookie.
+///
+/// The first non-empty value found wins, looking in order at
+/// 1. the `AOC_SESSION` environment variable
+/// 2. a `.session.cookie` file in the current directory or any of its parents
+/// 3. a `.session.cookie` file in the workspace root
+/// 4. `aoc/session.cookie` in the user config dir (`$XDG_CONFIG_HOME` or `~/.config`)
+///
+/// If nothing is found the error lists every location that was tried.
+pub fn find_session_cookie() -> Result<String, AocError> {
+    let cwd = std::env::current_dir().ok();
+    let candidates = candidate_files(cwd.as_deref(), &workspace_root(), config_dir());
+    lookup(std::env::var(SESSION_ENV).ok(), &candidates)
+}
+
+fn candidate_files(cwd: Option<&Path>, root: &Path, config: Option<PathBuf>) -> Vec<PathBuf> {
+    let mut candidates: Vec<PathBuf> = cwd
+        .into_iter()
+        .flat_map(Path::ancestors)
+        .map(|dir| dir.join(COOKIE_FILE))
+        .collect();
+
+    let root_cookie = root.join(COOKIE_FILE);
+    if !candidates.contains(&root_cookie) {
+        candidates.push(root_cookie);
+    }
+
+    candidates.extend(config.map(|dir| dir.join("aoc").join("session.cookie")));
+    candidates
+}
+
+fn lookup(env_value: Option<String>, candidates: &[PathBuf]) -> Result<String, AocError> {
+    if let Some(cookie) = env_value.as_deref().and_then(non_empty) {
+        return Ok(cookie);
+    }
+
+    for path in candidates {
+        if let Some(cookie) = read_to_string(path).ok().as_deref().and_then(non_empty) {
+            return Ok(cookie);
+        }
+    }
+
+    let tried = std::iter::once(format!("${}", SESSION_ENV))
+        .chain(candidates.iter().map(|path| path.display().to_string()))
+        .collect();
+    Err(AocError::MissingCookie { tried })
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{create_dir_all, write};
+
+    use tempfile::tempdir;
+
+    use crate::{
+        session::{candidate_files, lookup},
+        AocError,
+    };
+
+    #[test]
+    fn env_var_wins() {
+        let dir = tempdir().unwrap();
+        write(dir.path().join(".session.cookie"), "from-file").unwrap();
+        let candidates = candidate_files(Some(dir.path()), dir.path(), None);
+
+        let cookie = lookup(Some(" from-env\n".to_string()), &candidates).unwrap();
+
+        assert!(cookie == "from-env");
+    }
+
+    #[test]
+    fn can_find_cookie_in_a_parent_directory() {
+        let dir = tempdir().unwrap();
+        let crate_dir = dir.path().join("day3");
+        create_dir_all(&crate_dir).unwrap();
+        write(dir.path().join(".session.cookie"), "abc123\n").unwrap();
+        let candidates = candidate_files(Some(&crate_dir), &crate_dir, None);
+
+        assert!(lookup(None, &candidates).unwrap() == "abc123");
+    }
+
+    #[test]
+    fn can_find_cookie_in_config_dir() {
+        let dir = tempdir().unwrap();
+        let config = dir.path().join("config");
+        create_dir_all(config.join("aoc")).unwrap();
+        write(config.join("aoc").join("session.cookie"), "xyz").unwrap();
+        let candidates = candidate_files(None, &dir.path().join("root"), Some(config));
+
+        assert!(lookup(Some("".to_string()), &candidates).unwrap() == "xyz");
+    }
+
+    #[test]
+    fn lists_every_location_tried() {
+        let dir = tempdir().unwrap();
+        let cwd = dir.path().join("a");
+        let root = dir.path().join("workspace");
+        let candidates = candidate_files(Some(&cwd), &root, Some(dir.path().join("config")));
+
+        let Err(AocError::MissingCookie { tried }) = lookup(None, &candidates) else {
+            panic!("expected a missing cookie error");
+        };
+
+        assert!(tried[0] == "$AOC_SESSION");
+        assert!(tried.len() == candidates.len() + 1);
+        assert!(tried.contains(&root.join(".session.cookie").display().to_string()));
+        assert!(tried.last().unwrap().ends_with("session.cookie"));
+    }
+}
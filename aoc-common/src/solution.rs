@@ -0,0 +1,225 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{input::USAGE, AocError, InputSource, Part, Puzzle};
+
+/// The shared interface of every day: parse the input once, then solve
+/// both parts from it.
+///
+/// # Example
+/// ```ignore
+/// pub struct Day1;
+///
+/// impl Solution for Day1 {
+///     const PUZZLE: Puzzle = Puzzle::new(2022, 1);
+///     type Input = Vec<i32>;
+///
+///     fn parse(input: String) -> Result<Self::Input, AocError> { ... }
+///     fn part1(counts: &Self::Input) -> impl Display { ... }
+///     fn part2(counts: &Self::Input) -> impl Display { ... }
+/// }
+/// ```
+pub trait Solution {
+    const PUZZLE: Puzzle;
+
+    /// The parsed puzzle input, shared by both parts
+    type Input;
+
+    fn parse(input: String) -> Result<Self::Input, AocError>;
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    fn part2(input: &Self::Input) -> impl Display;
+}
+
+/// The answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    /// how long solving the part took, parsing not included
+    pub elapsed: Duration,
+}
+
+/// The result of running a [`Solution`] once
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub puzzle: Puzzle,
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Parses `input` and solves the requested `parts` of `S`, timing each step
+pub fn solve<S: Solution>(input: String, parts: &[Part]) -> Result<Solved, AocError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let value = match part {
+                Part::One => S::part1(&parsed).to_string(),
+                Part::Two => S::part2(&parsed).to_string(),
+            };
+            Answer {
+                part,
+                value,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Solved {
+        puzzle: S::PUZZLE,
+        parse_time,
+        answers,
+    })
+}
+
+/// Reads the input of `S` from the [`InputSource`] given on the command line
+/// (the cached input by default) and prints the answers to both parts,
+/// exiting with an error message if anything goes wrong.  This is all a
+/// day's `main` needs to do.
+pub fn run<S: Solution>() {
+    let mut args = std::env::args();
+    let program = args.next().unwrap_or_default();
+    let source = InputSource::from_args(args).unwrap_or_else(|e| {
+        eprintln!("{e}\nusage: {program} {USAGE}");
+        std::process::exit(2);
+    });
+
+    match source
+        .read(S::PUZZLE)
+        .and_then(|input| solve::<S>(input, &[Part::One, Part::Two]))
+    {
+        Ok(solved) => print_answers(&solved.answers),
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Prints `answer 1: ...` lines, answers spanning several lines (like a
+/// rendered screen) start on a line of their own
+pub fn print_answers(answers: &[Answer]) {
+    for answer in answers {
+        if answer.value.contains('\n') {
+            println!("answer {}:\n{}", answer.part.level(), answer.value);
+        } else {
+            println!("answer {}: {}", answer.part.level(), answer.value);
+        }
+    }
+}
+
+/// A [`Solution`] with its types erased, so days can be kept together in a
+/// [`Registry`]
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub puzzle: Puzzle,
+    solve: fn(String, &[Part]) -> Result<Solved, AocError>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Self {
+            puzzle: S::PUZZLE,
+            solve: solve::<S>,
+        }
+    }
+
+    pub fn solve(&self, input: String, parts: &[Part]) -> Result<Solved, AocError> {
+        (self.solve)(input, parts)
+    }
+}
+
+/// Every registered day, ordered by year and day
+///
+/// Day crates offer a `register` function that adds their [`Solution`]:
+/// ```ignore
+/// let mut registry = Registry::default();
+/// day1::register(&mut registry);
+/// ```
+#[derive(Clone, Default)]
+pub struct Registry {
+    days: BTreeMap<Puzzle, Day>,
+}
+
+impl Registry {
+    pub fn register<S: Solution>(&mut self) {
+        self.days.insert(S::PUZZLE, Day::of::<S>());
+    }
+
+    pub fn get(&self, puzzle: impl Into<Puzzle>) -> Option<&Day> {
+        self.days.get(&puzzle.into())
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = &Day> {
+        self.days.values()
+    }
+}
+
+/// A small [`Solution`] for the tests: one number per line, summed in
+/// part 1 and listed again in part 2
+#[cfg(test)]
+pub(crate) struct Sums;
+
+#[cfg(test)]
+impl Solution for Sums {
+    const PUZZLE: Puzzle = Puzzle::new(2015, 3);
+    type Input = Vec<i32>;
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        crate::transform_lines(Self::PUZZLE, &input, |l| l.parse::<i32>())
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        input.iter().sum::<i32>()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        solution::{solve, Registry, Solution, Sums},
+        AocError, Part,
+    };
+
+    #[test]
+    fn can_solve_selected_parts() {
+        let solved = solve::<Sums>("1\n5\n2".to_string(), &[Part::Two]).unwrap();
+
+        assert!(solved.puzzle == Sums::PUZZLE);
+        assert!(solved.answers.len() == 1);
+        assert!(solved.answers[0].part == Part::Two && solved.answers[0].value == "1\n5\n2");
+    }
+
+    #[test]
+    fn can_look_up_registered_days() {
+        let mut registry = Registry::default();
+        registry.register::<Sums>();
+
+        let day = registry.get(Sums::PUZZLE).unwrap();
+        let solved = day.solve("1\n2".to_string(), &[Part::One]).unwrap();
+
+        assert!(solved.answers[0].value == "3");
+        assert!(registry.get(1).is_none());
+        assert!(matches!(
+            day.solve("x".to_string(), &[Part::One]),
+            Err(AocError::Transform { line: 1, .. })
+        ));
+    }
+}
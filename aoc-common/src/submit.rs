@@ -0,0 +1,252 @@
+use std::{fmt::Display, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::AocClient,
+    html::{elements, text},
+    AocError, Ledger, Part, Puzzle,
+};
+
+/// What adventofcode.com said about a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// an answer was submitted too recently, try again after the duration
+    RateLimited(#[serde(with = "seconds")] Duration),
+    /// this part was already solved (or part 1 is still missing for part 2)
+    AlreadySolved,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::RateLimited(wait) => write!(f, "rate limited for {}s", wait.as_secs()),
+            Self::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Submits an answer for one part of a puzzle and reports the verdict.
+///
+/// Uses the same session cookie as the input fetching, see
+/// [`find_session_cookie`](crate::find_session_cookie).  Every submission
+/// is recorded in the day's [`Ledger`], and answers the ledger already
+/// knows to be wrong are refused with [`AocError::AnswerRejected`] without
+/// sending a request.
+///
+/// # Example
+/// ```ignore
+/// # use aoc_common::{submit_answer, Part, Verdict};
+/// let verdict = submit_answer(1, Part::One, 24000)?;
+///
+/// assert!(verdict == Verdict::Correct);
+/// ```
+pub fn submit_answer(
+    puzzle: impl Into<Puzzle>,
+    part: Part,
+    answer: impl Display,
+) -> Result<Verdict, AocError> {
+    let puzzle = puzzle.into();
+    let answer = answer.to_string();
+    let mut ledger = Ledger::load(puzzle)?;
+
+    ledger.check(puzzle, part, &answer)?;
+    let verdict = submit_answer_to(&AocClient::new()?, puzzle, part, &answer)?;
+    ledger.record(puzzle, part, &answer, verdict)?;
+
+    Ok(verdict)
+}
+
+pub(crate) fn submit_answer_to(
+    client: &AocClient,
+    puzzle: Puzzle,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, AocError> {
+    let level = part.level().to_string();
+    let html = client.post_form(
+        Some(puzzle),
+        &puzzle.answer_url(client.base_url()),
+        &[("level", level.as_str()), ("answer", answer)],
+    )?;
+
+    parse_verdict(&html)
+}
+
+fn parse_verdict(html: &str) -> Result<Verdict, AocError> {
+    let text = article_text(html);
+
+    // wrong answers also say "That's not the right answer", so the hints go first
+    if text.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::AlreadySolved)
+    } else if text.contains("You gave an answer too recently") {
+        Ok(Verdict::RateLimited(wait_time(&text).unwrap_or_default()))
+    } else if text.contains("your answer is too high") {
+        Ok(Verdict::TooHigh)
+    } else if text.contains("your answer is too low") {
+        Ok(Verdict::TooLow)
+    } else if text.contains("That's not the right answer") {
+        Ok(Verdict::Wrong)
+    } else {
+        Err(AocError::UnexpectedResponse(text))
+    }
+}
+
+/// The text of the `<article>` in the response, without any markup
+fn article_text(html: &str) -> String {
+    let article = elements(html, "<article", "article");
+    text(article.first().copied().unwrap_or(html))
+}
+
+/// Reads the "You have 4m 3s left to wait" part of a rate limited response
+fn wait_time(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split(' ')
+        .map(|part| {
+            let unit = match part.chars().last()? {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+            part[..part.len() - 1].parse::<u64>().ok().map(|n| n * unit)
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Stores the rate limit wait as whole seconds in the ledger
+mod seconds {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_secs())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use mockito::Matcher;
+
+    use crate::{
+        client::AocClient,
+        submit::{parse_verdict, submit_answer_to},
+        AocError, Part, Puzzle, Verdict,
+    };
+
+    fn response(article: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn can_parse_verdicts() {
+        let cases = [
+            (
+                "That's the right answer! You are <em>one gold star</em> closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure ...",
+                Verdict::Wrong,
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+        ];
+
+        for (article, verdict) in cases {
+            assert!(parse_verdict(&response(article)).unwrap() == verdict);
+        }
+    }
+
+    #[test]
+    fn can_parse_rate_limit_wait() {
+        let html = response("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 3s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a>");
+
+        assert!(parse_verdict(&html).unwrap() == Verdict::RateLimited(Duration::from_secs(243)));
+    }
+
+    #[test]
+    fn fail_on_unknown_response() {
+        let result = parse_verdict("<html>Puzzle inputs differ by user.</html>");
+
+        assert!(matches!(result, Err(AocError::UnexpectedResponse(_))));
+    }
+
+    #[test]
+    fn can_submit_to_mock_server() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/2022/day/1/answer")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("level".into(), "2".into()),
+                Matcher::UrlEncoded("answer".into(), "45000".into()),
+            ]))
+            .with_body(response(
+                "That's the right answer! You are <em>one gold star</em> closer.",
+            ))
+            .create();
+
+        let verdict = submit_answer_to(
+            &AocClient::with_base_url(&server.url()),
+            Puzzle::new(2022, 1),
+            Part::Two,
+            "45000",
+        )
+        .unwrap();
+
+        mock.assert();
+        assert!(verdict == Verdict::Correct);
+    }
+
+    #[test]
+    fn reports_server_errors() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("POST", "/2022/day/1/answer")
+            .with_status(500)
+            .create();
+
+        let result = submit_answer_to(
+            &AocClient::with_base_url(&server.url()),
+            Puzzle::new(2022, 1),
+            Part::One,
+            "1",
+        );
+
+        assert!(matches!(result, Err(AocError::HttpStatus(500))));
+    }
+}
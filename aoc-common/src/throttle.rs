@@ -0,0 +1,246 @@
+use std::{
+    fs::{read_to_string, write},
+    path::PathBuf,
+    thread::sleep,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::{paths::workspace_root, AocError, Puzzle};
+
+/// The shortest time between two requests, across every process using the
+/// same workspace
+const MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// The first back off after a 5xx response, doubled for every further one
+const BACKOFF: Duration = Duration::from_secs(30);
+const MAX_BACKOFF: Duration = Duration::from_secs(15 * 60);
+
+/// Puzzles unlock at midnight EST, which is 05:00 UTC
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// Keeps us a polite client of adventofcode.com.
+///
+/// The throttle state lives in `.aoc-throttle.toml` in the workspace root so
+/// that every day binary shares it.  There is no file locking, two processes
+/// starting a request in the same instant can both get through.
+pub(crate) struct Throttle {
+    state_path: Option<PathBuf>,
+    min_interval: Duration,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct ThrottleState {
+    /// milliseconds since the unix epoch
+    last_request: u64,
+    /// 5xx responses in a row
+    failures: u32,
+    /// milliseconds since the unix epoch
+    backoff_until: u64,
+}
+
+impl Throttle {
+    pub(crate) fn shared() -> Self {
+        Self {
+            state_path: Some(workspace_root().join(".aoc-throttle.toml")),
+            min_interval: MIN_INTERVAL,
+        }
+    }
+
+    /// A throttle that never waits, for tests against a mock server
+    #[cfg(test)]
+    pub(crate) fn disabled() -> Self {
+        Self {
+            state_path: None,
+            min_interval: Duration::ZERO,
+        }
+    }
+
+    /// Refuses requests for a puzzle that has not been released yet
+    pub(crate) fn check_unlocked(&self, puzzle: Puzzle) -> Result<(), AocError> {
+        let unlock = unlock_time(puzzle);
+        match unlock.duration_since(SystemTime::now()) {
+            Ok(wait) if !wait.is_zero() => Err(AocError::NotUnlocked { puzzle, wait }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Waits until the minimum interval since the last request has passed,
+    /// or fails if we are still backing off after server errors
+    pub(crate) fn wait_turn(&self) -> Result<(), AocError> {
+        let Some(path) = &self.state_path else {
+            return Ok(());
+        };
+        let mut state = self.load()?;
+
+        let now = now_millis();
+        if state.backoff_until > now {
+            let wait = Duration::from_millis(state.backoff_until - now);
+            return Err(AocError::Throttled { wait });
+        }
+
+        let next_request = state.last_request + self.min_interval.as_millis() as u64;
+        if next_request > now {
+            sleep(Duration::from_millis(next_request - now));
+        }
+
+        state.last_request = now_millis_rounded_up();
+        save(path, &state)
+    }
+
+    /// Starts (or extends) a back off after a server error, and clears it
+    /// again once the server answers normally
+    pub(crate) fn record(&self, status: StatusCode) -> Result<(), AocError> {
+        let Some(path) = &self.state_path else {
+            return Ok(());
+        };
+        let mut state = self.load()?;
+
+        if status.is_server_error() {
+            let backoff = BACKOFF
+                .saturating_mul(2_u32.saturating_pow(state.failures))
+                .min(MAX_BACKOFF);
+            state.failures += 1;
+            state.backoff_until = now_millis() + backoff.as_millis() as u64;
+        } else {
+            state.failures = 0;
+            state.backoff_until = 0;
+        }
+        save(path, &state)
+    }
+
+    fn load(&self) -> Result<ThrottleState, AocError> {
+        let Some(path) = self.state_path.as_ref().filter(|path| path.exists()) else {
+            return Ok(ThrottleState::default());
+        };
+        let content = read_to_string(path).map_err(|e| AocError::io(path, e))?;
+        // a broken state file should not lock us out, start over instead
+        Ok(toml::from_str(&content).unwrap_or_default())
+    }
+}
+
+fn save(path: &PathBuf, state: &ThrottleState) -> Result<(), AocError> {
+    let content = toml::to_string(state).unwrap_or_default();
+    write(path, content).map_err(|e| AocError::io(path, e))
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// The state file only keeps whole milliseconds, request times are rounded
+/// up so the next request never comes sooner than the minimum interval
+fn now_millis_rounded_up() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos().div_ceil(1_000_000) as u64)
+        .unwrap_or_default()
+}
+
+/// When a puzzle is released: midnight EST on December `day`
+pub(crate) fn unlock_time(puzzle: Puzzle) -> SystemTime {
+    let days = days_from_civil(puzzle.year as i64, 12, puzzle.day as i64);
+    let seconds = days * 86_400 + (UNLOCK_HOUR_UTC * 3600) as i64;
+    UNIX_EPOCH + Duration::from_secs(seconds.max(0) as u64)
+}
+
+/// Days since 1970-01-01 for a date in the proleptic Gregorian calendar,
+/// see <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::read_to_string,
+        time::{Duration, Instant, UNIX_EPOCH},
+    };
+
+    use reqwest::StatusCode;
+    use tempfile::tempdir;
+
+    use crate::{
+        throttle::{unlock_time, Throttle, ThrottleState},
+        AocError, Puzzle,
+    };
+
+    fn throttle_in(dir: &tempfile::TempDir, min_interval: Duration) -> Throttle {
+        Throttle {
+            state_path: Some(dir.path().join(".aoc-throttle.toml")),
+            min_interval,
+        }
+    }
+
+    #[test]
+    fn knows_when_puzzles_unlock() {
+        // 2022-12-01T05:00:00Z
+        assert!(
+            unlock_time(Puzzle::new(2022, 1)) == UNIX_EPOCH + Duration::from_secs(1_669_870_800)
+        );
+        // 2015-12-25T05:00:00Z
+        assert!(
+            unlock_time(Puzzle::new(2015, 25)) == UNIX_EPOCH + Duration::from_secs(1_451_019_600)
+        );
+    }
+
+    #[test]
+    fn refuses_locked_puzzles() {
+        let throttle = Throttle::disabled();
+
+        assert!(throttle.check_unlocked(Puzzle::new(2022, 12)).is_ok());
+        assert!(matches!(
+            throttle.check_unlocked(Puzzle::new(9999, 1)),
+            Err(AocError::NotUnlocked { .. })
+        ));
+    }
+
+    #[test]
+    fn waits_the_minimum_interval_between_requests() {
+        let dir = tempdir().unwrap();
+        let throttle = throttle_in(&dir, Duration::from_millis(200));
+
+        let start = Instant::now();
+        throttle.wait_turn().unwrap();
+        throttle.wait_turn().unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        // a second process shares the same state file
+        let other = throttle_in(&dir, Duration::from_millis(200));
+        let start = Instant::now();
+        other.wait_turn().unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    }
+
+    #[test]
+    fn backs_off_after_server_errors() {
+        let dir = tempdir().unwrap();
+        let throttle = throttle_in(&dir, Duration::ZERO);
+
+        throttle.record(StatusCode::BAD_GATEWAY).unwrap();
+        let Err(AocError::Throttled { wait }) = throttle.wait_turn() else {
+            panic!("expected to be throttled");
+        };
+        assert!(wait > Duration::from_secs(25) && wait <= Duration::from_secs(30));
+
+        throttle.record(StatusCode::SERVICE_UNAVAILABLE).unwrap();
+        let content = read_to_string(dir.path().join(".aoc-throttle.toml")).unwrap();
+        let state: ThrottleState = toml::from_str(&content).unwrap();
+        assert!(state.failures == 2);
+
+        throttle.record(StatusCode::OK).unwrap();
+        assert!(throttle.wait_turn().is_ok());
+    }
+}
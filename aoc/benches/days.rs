@@ -0,0 +1,24 @@
+//! `cargo bench -p aoc` times every day against its cached input (or the
+//! example when there is none) and prints a Markdown table
+
+use aoc::registry;
+use aoc_common::{bench, markdown_table, offline_input};
+
+const RUNS: usize = 10;
+
+fn main() {
+    let mut benches = vec![];
+
+    for day in registry().days() {
+        let Some((input, content)) = offline_input(day.puzzle) else {
+            eprintln!("{}: no cached input or example, skipping", day.puzzle);
+            continue;
+        };
+        match bench(day, input, &content, RUNS) {
+            Ok(result) => benches.push(result),
+            Err(e) => eprintln!("{}: {}", day.puzzle, e),
+        }
+    }
+
+    println!("{}", markdown_table(&benches));
+}
@@ -0,0 +1,60 @@
+mod scaffold;
+mod table;
+
+use aoc_common::Registry;
+
+pub use scaffold::new_day;
+pub use table::render_table;
+
+/// Every day of the workspace, each day crate registers its own solution
+pub fn registry() -> Registry {
+    let mut registry = Registry::default();
+
+    day1::register(&mut registry);
+    day2::register(&mut registry);
+    day3::register(&mut registry);
+    day4::register(&mut registry);
+    day5::register(&mut registry);
+    day6::register(&mut registry);
+    day7::register(&mut registry);
+    day8::register(&mut registry);
+    day9::register(&mut registry);
+    day10::register(&mut registry);
+    day11::register(&mut registry);
+    day12::register(&mut registry);
+
+    registry
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read_to_string;
+
+    use aoc_common::Part;
+
+    use crate::registry;
+
+    #[test]
+    fn has_every_day() {
+        let days = registry()
+            .days()
+            .map(|day| day.puzzle.day)
+            .collect::<Vec<_>>();
+
+        assert!(days.len() >= 12);
+        assert!(days == (1..=days.len() as i32).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn can_solve_through_the_registry() {
+        let input = read_to_string("../day1/inputs/test_input.txt").unwrap();
+        let solved = registry()
+            .get(1)
+            .unwrap()
+            .solve(input, &[Part::One, Part::Two])
+            .unwrap();
+
+        assert!(solved.answers[0].value == "24000");
+        assert!(solved.answers[1].value == "45000");
+    }
+}
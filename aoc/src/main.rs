@@ -0,0 +1,283 @@
+use std::{
+    fs::write,
+    path::{Path, PathBuf},
+};
+
+use aoc::{new_day, registry, render_table};
+use aoc_common::{
+    bench, create_cache_key, encrypt_cached_input, fetch_leaderboard, markdown_table,
+    offline_input, refresh_input, AocError, Day, InputSource, Part, Puzzle, Registry, DEFAULT_YEAR,
+};
+use clap::{Args, Parser, Subcommand};
+
+/// Runs the Advent of Code solutions of this workspace
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with --all
+    Run(RunArgs),
+    /// Time parsing and both parts of every day against cached inputs or
+    /// examples, printing a Markdown table
+    Bench(BenchArgs),
+    /// Create the crate, example input and book chapter of a new day
+    NewDay(NewDayArgs),
+    /// Download the input of a day again, replacing the cached one once the
+    /// download checks out
+    Refresh(RefreshArgs),
+    /// Encrypt the cached inputs, creating the cache key first if there is
+    /// none yet
+    EncryptCache(EncryptCacheArgs),
+    /// Show the standings of a private leaderboard, fetched at most every
+    /// 15 minutes
+    Leaderboard(LeaderboardArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// the day to solve
+    #[arg(long, required_unless_present = "all")]
+    day: Option<i32>,
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    year: i32,
+    /// only solve this part (1 or 2)
+    #[arg(long, value_parser = parse_part)]
+    part: Option<Part>,
+    /// solve every registered day
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+    /// read the input from this file (or stdin for -) instead of the cached
+    /// download
+    #[arg(long, conflicts_with = "example")]
+    input: Option<InputSource>,
+    /// use the example input in day{N}/inputs/test_input.txt
+    #[arg(long)]
+    example: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// only benchmark this day
+    #[arg(long)]
+    day: Option<i32>,
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    year: i32,
+    /// how often to solve each day
+    #[arg(long, default_value_t = 10)]
+    runs: usize,
+    /// also write the Markdown table to this file
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewDayArgs {
+    /// the day to create
+    #[arg(long, value_parser = clap::value_parser!(i32).range(1..=25))]
+    day: i32,
+}
+
+#[derive(Args)]
+struct RefreshArgs {
+    /// the day to download again
+    #[arg(long)]
+    day: i32,
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    year: i32,
+}
+
+#[derive(Args)]
+struct EncryptCacheArgs {
+    /// only encrypt the input of this day
+    #[arg(long)]
+    day: Option<i32>,
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    year: i32,
+}
+
+#[derive(Args)]
+struct LeaderboardArgs {
+    /// the id of the leaderboard, the number at the end of its URL
+    #[arg(long)]
+    id: u64,
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    year: i32,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench_days(args),
+        Command::NewDay(args) => scaffold(args),
+        Command::Refresh(args) => refresh(args),
+        Command::EncryptCache(args) => encrypt_cache(args),
+        Command::Leaderboard(args) => leaderboard(args),
+    };
+    if let Err(e) = result {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), AocError> {
+    let registry = registry();
+    let day = args.day.filter(|_| !args.all);
+    let days = select_days(&registry, args.year, day)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let source = match args.input {
+        Some(source) => source,
+        None if args.example => InputSource::Example,
+        None => InputSource::Cached,
+    };
+
+    let mut solved = vec![];
+    let mut failed = vec![];
+    for day in days {
+        match source
+            .read(day.puzzle)
+            .and_then(|input| day.solve(input, &parts))
+        {
+            Ok(result) => solved.push(result),
+            Err(e) => {
+                eprintln!("{e}");
+                failed.push(day.puzzle);
+            }
+        }
+    }
+
+    if !solved.is_empty() {
+        println!("{}", render_table(&solved));
+    }
+    failures(failed)
+}
+
+/// Fails with the puzzles in `failed`, whose errors are printed already
+fn failures(failed: Vec<Puzzle>) -> Result<(), AocError> {
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(AocError::Failed(failed))
+    }
+}
+
+/// The registered `day` of `year`, or every registered day of `year`
+fn select_days(registry: &Registry, year: i32, day: Option<i32>) -> Result<Vec<&Day>, AocError> {
+    match day {
+        Some(day) => {
+            let puzzle = Puzzle::new(year, day);
+            Ok(vec![registry
+                .get(puzzle)
+                .ok_or(AocError::NoSolution(puzzle))?])
+        }
+        None => {
+            let days = registry
+                .days()
+                .filter(|day| day.puzzle.year == year)
+                .collect::<Vec<_>>();
+            if days.is_empty() {
+                Err(AocError::NoSolutions(year))
+            } else {
+                Ok(days)
+            }
+        }
+    }
+}
+
+fn parse_part(level: &str) -> Result<Part, String> {
+    level.parse::<u8>().map_err(|e| e.to_string())?.try_into()
+}
+
+fn bench_days(args: BenchArgs) -> Result<(), AocError> {
+    let registry = registry();
+    let days = select_days(&registry, args.year, args.day)?;
+
+    let mut benches = vec![];
+    let mut failed = vec![];
+    for day in days {
+        let Some((input, content)) = offline_input(day.puzzle) else {
+            eprintln!("{}: no cached input or example, skipping", day.puzzle);
+            continue;
+        };
+        match bench(day, input, &content, args.runs) {
+            Ok(result) => benches.push(result),
+            Err(e) => {
+                eprintln!("{e}, skipping");
+                failed.push(day.puzzle);
+            }
+        }
+    }
+
+    let table = markdown_table(&benches);
+    println!("{table}");
+    if let Some(path) = args.output {
+        write(&path, format!("{table}\n")).map_err(|source| AocError::Io { path, source })?;
+    }
+    failures(failed)
+}
+
+fn scaffold(args: NewDayArgs) -> Result<(), AocError> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the aoc crate lives in the workspace");
+
+    for path in new_day(root, Puzzle::new(DEFAULT_YEAR, args.day))? {
+        println!("{}", path.strip_prefix(root).unwrap_or(&path).display());
+    }
+    Ok(())
+}
+
+fn refresh(args: RefreshArgs) -> Result<(), AocError> {
+    let puzzle = Puzzle::new(args.year, args.day);
+    let input = refresh_input(puzzle)?;
+
+    println!("{}: downloaded {} lines", puzzle, input.lines().count());
+    Ok(())
+}
+
+fn encrypt_cache(args: EncryptCacheArgs) -> Result<(), AocError> {
+    if let Some(path) = create_cache_key()? {
+        println!(
+            "created the cache key {}, keep it: encrypted inputs can not be read without it",
+            path.display()
+        );
+    }
+
+    let puzzles: Vec<Puzzle> = match args.day {
+        Some(day) => vec![Puzzle::new(args.year, day)],
+        None => registry()
+            .days()
+            .map(|day| day.puzzle)
+            .filter(|puzzle| puzzle.year == args.year)
+            .collect(),
+    };
+    let mut failed = vec![];
+    for puzzle in puzzles {
+        match encrypt_cached_input(puzzle) {
+            Ok(Some(path)) => println!("{}: encrypted {}", puzzle, path.display()),
+            Ok(None) => {}
+            Err(e) => {
+                eprintln!("{e}");
+                failed.push(puzzle);
+            }
+        }
+    }
+    failures(failed)
+}
+
+fn leaderboard(args: LeaderboardArgs) -> Result<(), AocError> {
+    let leaderboard = fetch_leaderboard(args.year, args.id)?;
+
+    println!("{}", leaderboard.standings());
+    Ok(())
+}
@@ -0,0 +1,255 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{AocError, Puzzle, DEFAULT_YEAR};
+
+/// Creates the `day{day}` crate for `puzzle` in the workspace at `root` and
+/// wires it up everywhere a day has to be known:
+///
+/// - `day{day}/` with a `Solution` skeleton, an empty example input and a
+///   `known_answers.toml` without answers yet
+/// - the workspace `members` in `Cargo.toml`
+/// - the `aoc` crate's dependencies and `registry()`
+/// - a chapter in `aoc-2022-book/src/SUMMARY.md`
+///
+/// Returns the files that were created or changed.  The day crates are not
+/// named by year, so only days of [`DEFAULT_YEAR`] can be created.
+pub fn new_day(root: &Path, puzzle: Puzzle) -> Result<Vec<PathBuf>, AocError> {
+    let name = format!("day{}", puzzle.day);
+    let dir = root.join(&name);
+    if puzzle.year != DEFAULT_YEAR {
+        let message = format!("this workspace only holds the days of {}", DEFAULT_YEAR);
+        return Err(AocError::Scaffold { path: dir, message });
+    }
+    if dir.exists() {
+        let message = "refusing to overwrite a day".to_string();
+        return Err(AocError::Scaffold { path: dir, message });
+    }
+
+    let files = [
+        (dir.join("Cargo.toml"), cargo_toml(&name)),
+        (dir.join("src").join("lib.rs"), lib_rs(puzzle)),
+        (dir.join("src").join("main.rs"), main_rs(puzzle)),
+        (dir.join("inputs").join("test_input.txt"), String::new()),
+        (
+            dir.join("known_answers.toml"),
+            "[[case]]\ninput = \"example\"\n".to_string(),
+        ),
+    ];
+    let mut changed = vec![];
+    for (path, content) in files {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent).map_err(|source| AocError::io(parent, source))?;
+        }
+        write(&path, content).map_err(|source| AocError::io(&path, source))?;
+        changed.push(path);
+    }
+
+    changed.push(edit(&root.join("Cargo.toml"), |s| add_member(s, &name))?);
+    changed.push(edit(&root.join("aoc").join("Cargo.toml"), |s| {
+        add_after_last(
+            s,
+            "day",
+            &format!("{} = {{ path = \"../{}\" }}", name, name),
+        )
+    })?);
+    changed.push(edit(&root.join("aoc").join("src").join("lib.rs"), |s| {
+        add_after_last(
+            s,
+            "    day",
+            &format!("    {}::register(&mut registry);", name),
+        )
+    })?);
+    changed.push(edit(
+        &root.join("aoc-2022-book").join("src").join("SUMMARY.md"),
+        |s| Some(add_chapter(s, puzzle.day)),
+    )?);
+
+    Ok(changed)
+}
+
+/// Rewrites the file at `path` with `change`, which returns `None` when it
+/// does not recognise the content
+fn edit(path: &Path, change: impl Fn(&str) -> Option<String>) -> Result<PathBuf, AocError> {
+    let content = read_to_string(path).map_err(|source| AocError::io(path, source))?;
+    let changed = change(&content).ok_or_else(|| AocError::Scaffold {
+        path: path.to_path_buf(),
+        message: "unexpected layout, add the day by hand".to_string(),
+    })?;
+    write(path, changed).map_err(|source| AocError::io(path, source))?;
+    Ok(path.to_path_buf())
+}
+
+/// Adds `name` as the last entry of the workspace `members` list
+fn add_member(cargo_toml: &str, name: &str) -> Option<String> {
+    let start = cargo_toml.find("members = [")?;
+    let end = start + cargo_toml[start..].find(']')?;
+    let members = cargo_toml[start..end].trim_end().trim_end_matches(',');
+
+    Some(format!(
+        "{}{},\n  \"{}\"\n{}",
+        &cargo_toml[..start],
+        members,
+        name,
+        &cargo_toml[end..]
+    ))
+}
+
+/// Inserts `line` after the last line starting with `prefix`
+fn add_after_last(content: &str, prefix: &str, line: &str) -> Option<String> {
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let idx = lines.iter().rposition(|l| l.starts_with(prefix))?;
+    lines.insert(idx + 1, line);
+    Some(lines.join("\n") + "\n")
+}
+
+fn add_chapter(summary: &str, day: i32) -> String {
+    format!(
+        "{}\n\n- [Day {day}](./day_{day}/day_{day}.md)\n    \
+         * [part 1](./day_{day}/day_{day}_part_1.md)\n    \
+         * [part 2](./day_{day}/day_{day}_part_2.md)\n",
+        summary.trim_end(),
+        day = day
+    )
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+"aoc-common" = {{ path = "../aoc-common" }}
+"#
+    )
+}
+
+fn lib_rs(puzzle: Puzzle) -> String {
+    let Puzzle { year, day } = puzzle;
+    format!(
+        r#"use std::fmt::Display;
+
+use aoc_common::{{AocError, Puzzle, Registry, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const PUZZLE: Puzzle = Puzzle::new({year}, {day});
+    type Input = Vec<String>;
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {{
+        Ok(input.lines().map(|s| s.to_string()).collect())
+    }}
+
+    fn part1(input: &Self::Input) -> impl Display {{
+        input.len()
+    }}
+
+    fn part2(input: &Self::Input) -> impl Display {{
+        input.len()
+    }}
+}}
+
+pub fn register(registry: &mut Registry) {{
+    registry.register::<Day{day}>();
+}}
+
+#[cfg(test)]
+mod tests {{
+    use aoc_common::{{get_test_input, Solution}};
+
+    use crate::Day{day};
+
+    const TEST_FILE: &str = "inputs/test_input.txt";
+
+    #[test]
+    fn can_read_input() {{
+        let input = get_test_input(TEST_FILE, Day{day}::parse);
+
+        assert!(input.is_ok());
+    }}
+}}
+"#
+    )
+}
+
+fn main_rs(puzzle: Puzzle) -> String {
+    let day = puzzle.day;
+    format!(
+        r#"use aoc_common::run;
+use day{day}::Day{day};
+
+fn main() {{
+    run::<Day{day}>();
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{create_dir_all, read_to_string, write};
+
+    use aoc_common::{AocError, Puzzle};
+    use tempfile::tempdir;
+
+    use crate::scaffold::new_day;
+
+    #[test]
+    fn can_scaffold_a_day() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        create_dir_all(root.join("aoc/src")).unwrap();
+        create_dir_all(root.join("aoc-2022-book/src")).unwrap();
+        write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n  \"aoc\",\n  \"day1\"\n]",
+        )
+        .unwrap();
+        write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\nday1 = { path = \"../day1\" }\n\n[[bench]]\n",
+        )
+        .unwrap();
+        write(
+            root.join("aoc/src/lib.rs"),
+            "fn registry() {\n    day1::register(&mut registry);\n\n    registry\n}\n",
+        )
+        .unwrap();
+        write(root.join("aoc-2022-book/src/SUMMARY.md"), "# Summary\n").unwrap();
+
+        let changed = new_day(root, Puzzle::new(2022, 13)).unwrap();
+
+        assert!(changed.len() == 9);
+        assert!(read_to_string(root.join("day13/inputs/test_input.txt")).unwrap() == "");
+        assert!(read_to_string(root.join("day13/src/lib.rs"))
+            .unwrap()
+            .contains("const PUZZLE: Puzzle = Puzzle::new(2022, 13);"));
+        assert!(
+            read_to_string(root.join("Cargo.toml")).unwrap()
+                == "[workspace]\nmembers = [\n  \"aoc\",\n  \"day1\",\n  \"day13\"\n]"
+        );
+        assert!(read_to_string(root.join("aoc/Cargo.toml"))
+            .unwrap()
+            .starts_with(
+                "[dependencies]\nday1 = { path = \"../day1\" }\nday13 = { path = \"../day13\" }\n"
+            ));
+        assert!(read_to_string(root.join("aoc/src/lib.rs"))
+            .unwrap()
+            .contains("day1::register(&mut registry);\n    day13::register(&mut registry);\n"));
+        assert!(read_to_string(root.join("aoc-2022-book/src/SUMMARY.md"))
+            .unwrap()
+            .ends_with("- [Day 13](./day_13/day_13.md)\n    * [part 1](./day_13/day_13_part_1.md)\n    * [part 2](./day_13/day_13_part_2.md)\n"));
+
+        let again = new_day(root, Puzzle::new(2022, 13));
+        assert!(matches!(again, Err(AocError::Scaffold { .. })));
+
+        let other_year = new_day(root, Puzzle::new(2021, 14));
+        assert!(matches!(other_year, Err(AocError::Scaffold { .. })));
+        assert!(!root.join("day14").exists());
+    }
+}
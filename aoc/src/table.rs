@@ -0,0 +1,107 @@
+use aoc_common::Solved;
+
+const HEADER: [&str; 4] = ["puzzle", "part", "answer", "time"];
+
+/// Renders the answers and timings of every run as aligned columns, one row
+/// for parsing and one per part.  Answers spanning several lines continue on
+/// rows of their own.
+pub fn render_table(results: &[Solved]) -> String {
+    let mut rows: Vec<[String; 4]> = vec![HEADER.map(|h| h.to_string())];
+
+    for solved in results {
+        rows.push([
+            solved.puzzle.to_string(),
+            "parse".to_string(),
+            String::new(),
+            format!("{:.1?}", solved.parse_time),
+        ]);
+        for answer in &solved.answers {
+            let mut lines = answer.value.lines();
+            rows.push([
+                String::new(),
+                answer.part.level().to_string(),
+                lines.next().unwrap_or_default().to_string(),
+                format!("{:.1?}", answer.elapsed),
+            ]);
+            for line in lines {
+                rows.push([
+                    String::new(),
+                    String::new(),
+                    line.to_string(),
+                    String::new(),
+                ]);
+            }
+        }
+    }
+
+    let widths = (0..HEADER.len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<usize>>();
+
+    rows.iter()
+        .map(|row| {
+            format!(
+                "{:<w0$}  {:<w1$}  {:<w2$}  {:>w3$}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3],
+            )
+            .trim_end()
+            .to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_common::{Answer, Part, Puzzle, Solved};
+
+    use crate::table::render_table;
+
+    #[test]
+    fn can_render_answers_and_timings() {
+        let solved = Solved {
+            puzzle: Puzzle::new(2022, 10),
+            parse_time: Duration::from_micros(12),
+            answers: vec![
+                Answer {
+                    part: Part::One,
+                    value: "13140".to_string(),
+                    elapsed: Duration::from_millis(3),
+                },
+                Answer {
+                    part: Part::Two,
+                    value: "##..\n#..#".to_string(),
+                    elapsed: Duration::from_nanos(800),
+                },
+            ],
+        };
+
+        let table = render_table(&[solved]);
+
+        assert!(
+            table
+                == [
+                    "puzzle       part   answer     time",
+                    "2022 day 10  parse           12.0µs",
+                    "             1      13140     3.0ms",
+                    "             2      ##..    800.0ns",
+                    "                    #..#",
+                ]
+                .join("\n")
+        );
+    }
+}
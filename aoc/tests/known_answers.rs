@@ -0,0 +1,29 @@
+//! Runs every day against the answers in its `known_answers.toml`
+
+use aoc::registry;
+use aoc_common::{KnownAnswers, Outcome};
+
+#[test]
+fn every_day_still_gives_its_known_answers() {
+    let mut changed = vec![];
+
+    for day in registry().days() {
+        let known = KnownAnswers::load(day.puzzle)
+            .unwrap()
+            .unwrap_or_else(|| panic!("{} has no known_answers.toml", day.puzzle));
+
+        for check in known.check(day).unwrap() {
+            match check.outcome {
+                Outcome::Matched => {}
+                Outcome::Skipped => eprintln!("{check}"),
+                Outcome::Changed { .. } => changed.push(check.to_string()),
+            }
+        }
+    }
+
+    assert!(
+        changed.is_empty(),
+        "answers changed:\n\n{}\n",
+        changed.join("\n\n")
+    );
+}
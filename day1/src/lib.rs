@@ -0,0 +1,92 @@
+use std::fmt::Display;
+
+use aoc_common::{transform_lines, AocError, Puzzle, Registry, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const PUZZLE: Puzzle = Puzzle::new(2022, 1);
+    type Input = Vec<i32>;
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        let calories = read_calories(&input)?;
+        let mut counts = get_elf_counts(&calories);
+        counts.sort();
+        counts.reverse();
+        Ok(counts)
+    }
+
+    fn part1(counts: &Self::Input) -> impl Display {
+        counts[0]
+    }
+
+    fn part2(counts: &Self::Input) -> impl Display {
+        counts.iter().take(3).sum::<i32>()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day1>();
+}
+
+/// One entry per line, `None` for the blank lines between elves
+fn read_calories(input: &str) -> Result<Vec<Option<i32>>, AocError> {
+    transform_lines(Day1::PUZZLE, input, |line| match line.trim() {
+        "" => Ok(None),
+        calories => calories.parse::<i32>().map(Some),
+    })
+}
+
+fn get_elf_counts(calories: &[Option<i32>]) -> Vec<i32> {
+    calories
+        .split(Option::is_none)
+        .map(|elf| elf.iter().flatten().sum::<i32>())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::fs::read_to_string;
+
+    use super::*;
+
+    fn get_input(filename: &str) -> Vec<Option<i32>> {
+        read_calories(&read_to_string(filename).expect("could not read input file")).unwrap()
+    }
+
+    #[test]
+    fn fail_on_bad_calories() {
+        let result = Day1::parse("1000\n2000\n\n3x00\n".to_string());
+
+        assert!(matches!(result, Err(AocError::Transform { line: 4, .. })));
+    }
+
+    #[test]
+    fn can_get_elf_counts() {
+        let input = get_input("inputs/test_input.txt");
+        let counts = get_elf_counts(&input);
+
+        assert!(counts == vec![6000, 4000, 11000, 24000, 10000]);
+    }
+
+    #[test]
+    fn can_find_max() {
+        let input = get_input("inputs/test_input.txt");
+        let mut counts = get_elf_counts(&input);
+        counts.sort();
+
+        assert!(*counts.last().unwrap() == 24000)
+    }
+
+    #[test]
+    fn can_sum_top_three() {
+        let input = get_input("inputs/test_input.txt");
+        let mut counts = get_elf_counts(&input);
+        counts.sort();
+        counts.reverse();
+        let top_three_sum = counts.iter().take(3).sum::<i32>();
+
+        assert!(top_three_sum == 45000);
+    }
+}
@@ -0,0 +1,367 @@
+use std::{fmt::Display, str::FromStr};
+
+use aoc_common::{transform_blocks, AocError, Puzzle, Registry, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const PUZZLE: Puzzle = Puzzle::new(2022, 11);
+    type Input = Vec<Monkey>;
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        let count = input.trim_end().split("\n\n").count();
+        if count < 2 {
+            return Err(AocError::Transform {
+                puzzle: Self::PUZZLE,
+                line: 1,
+                message: "Expected at least two monkeys".to_string(),
+            });
+        }
+
+        transform_blocks(Self::PUZZLE, &input, |s| {
+            let monkey = s.parse::<Monkey>()?;
+            for target in [monkey.test.if_true, monkey.test.if_false] {
+                if target >= count as u64 {
+                    return Err(MonkeyParsingError::with(&format!(
+                        "there is no monkey {} to throw to",
+                        target
+                    )));
+                }
+            }
+            Ok(monkey)
+        })
+    }
+
+    fn part1(monkeys: &Self::Input) -> impl Display {
+        get_answer(monkeys.clone(), 20, true)
+    }
+
+    fn part2(monkeys: &Self::Input) -> impl Display {
+        get_answer(monkeys.clone(), 10_000, false)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day11>();
+}
+
+fn get_answer(mut monkeys: Vec<Monkey>, how_many_rounds: i32, reduce_worry: bool) -> u64 {
+    let divisor_product = monkeys.iter().map(|m| m.test.divisible_by).product::<u64>();
+    let mut new_monkeys = rounds(how_many_rounds, &mut monkeys, reduce_worry, divisor_product);
+
+    new_monkeys.sort_by_key(|m| m.inpsected_count());
+
+    let top_two = new_monkeys.iter().rev().take(2).collect::<Vec<&Monkey>>();
+
+    top_two[0].inpsected_count() * top_two[1].inpsected_count()
+}
+
+fn monkey_round(monkey: &Monkey, reduce_worry: bool, divisor_product: u64) -> Vec<ItemDestination> {
+    let mut items_and_destinations: Vec<ItemDestination> = vec![];
+
+    for mut item in monkey.items.iter().copied() {
+        item %= divisor_product;
+        let mut worry_level = match monkey.operation.operator {
+            Operator::Add => {
+                item + match monkey.operation.value.clone() {
+                    OperationValue::Value(v) => v,
+                    OperationValue::Old => item,
+                }
+            }
+            Operator::Multiply => {
+                let value = match monkey.operation.value.clone() {
+                    OperationValue::Value(v) => v,
+                    OperationValue::Old => item,
+                };
+                item * value
+            }
+        };
+        if reduce_worry {
+            worry_level /= 3;
+        }
+
+        let is_divisible = worry_level % monkey.test.divisible_by == 0;
+
+        let destination = if is_divisible {
+            monkey.test.if_true
+        } else {
+            monkey.test.if_false
+        };
+
+        items_and_destinations.push(ItemDestination {
+            item: worry_level,
+            destination: destination.try_into().unwrap(),
+        });
+    }
+    items_and_destinations
+}
+
+fn round(
+    monkeys: &mut [Monkey],
+    idx: usize,
+    reduce_worry: bool,
+    divisor_product: u64,
+) -> Vec<Monkey> {
+    if idx == monkeys.len() {
+        monkeys.to_vec()
+    } else {
+        let items_and_destinations = monkey_round(&monkeys[idx], reduce_worry, divisor_product);
+        for item in items_and_destinations {
+            monkeys[idx].increase_inspected(item.destination);
+            monkeys[item.destination].items.push(item.item);
+        }
+        monkeys[idx].items = vec![];
+        round(monkeys, idx + 1, reduce_worry, divisor_product)
+    }
+}
+
+fn rounds(
+    how_many: i32,
+    monkeys: &mut [Monkey],
+    reduce_worry: bool,
+    divisor_product: u64,
+) -> Vec<Monkey> {
+    if how_many == 0 {
+        monkeys.to_vec()
+    } else {
+        rounds(
+            how_many - 1,
+            &mut round(monkeys, 0, reduce_worry, divisor_product),
+            reduce_worry,
+            divisor_product,
+        )
+    }
+}
+
+struct ItemDestination {
+    item: u64,
+    destination: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Operation {
+    operator: Operator,
+    value: OperationValue,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug, Clone)]
+enum OperationValue {
+    Value(u64),
+    Old,
+}
+
+#[derive(Debug, Clone)]
+struct Test {
+    divisible_by: u64,
+    if_true: u64,
+    if_false: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    test: Test,
+    inspection_count: Vec<u64>,
+}
+
+impl Monkey {
+    fn inpsected_count(&self) -> u64 {
+        self.inspection_count.iter().sum()
+    }
+
+    fn increase_inspected(&mut self, idx: usize) {
+        if idx < self.inspection_count.len() {
+            self.inspection_count[idx] += 1;
+        } else {
+            for _ in self.inspection_count.len()..idx {
+                self.inspection_count.push(0);
+            }
+            self.inspection_count.push(1);
+        }
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = MonkeyParsingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.trim().split('\n').collect::<Vec<_>>();
+        let items = field(&lines, 1, "Starting items: ")?
+            .split(", ")
+            .map(number)
+            .collect::<Result<Vec<u64>, _>>()?;
+
+        let operation_parts = field(&lines, 2, "Operation: new = old ")?
+            .split(' ')
+            .collect::<Vec<&str>>();
+
+        let operator = match operation_parts[0] {
+            "+" => Operator::Add,
+            "*" => Operator::Multiply,
+            other => return Err(MonkeyParsingError::with(other)),
+        };
+
+        let value = match operation_parts.get(1) {
+            Some(&"old") => OperationValue::Old,
+            Some(v) => OperationValue::Value(number(v)?),
+            None => return Err(MonkeyParsingError::with(lines[2])),
+        };
+
+        let operation = Operation { operator, value };
+
+        let divisible_by = field(&lines, 3, "Test: divisible by ")?;
+        let divisible_by = match number(divisible_by)? {
+            0 => return Err(MonkeyParsingError::with(divisible_by)),
+            divisible_by => divisible_by,
+        };
+        let if_true = number(field(&lines, 4, "If true: throw to monkey ")?)?;
+        let if_false = number(field(&lines, 5, "If false: throw to monkey ")?)?;
+
+        let test = Test {
+            divisible_by,
+            if_true,
+            if_false,
+        };
+
+        Ok(Self {
+            items,
+            operation,
+            test,
+            inspection_count: vec![],
+        })
+    }
+}
+
+fn field<'a>(lines: &[&'a str], idx: usize, prefix: &str) -> Result<&'a str, MonkeyParsingError> {
+    let line = lines
+        .get(idx)
+        .ok_or_else(|| MonkeyParsingError::with(prefix.trim()))?;
+    line.trim()
+        .strip_prefix(prefix)
+        .ok_or_else(|| MonkeyParsingError::with(line.trim()))
+}
+
+fn number(s: &str) -> Result<u64, MonkeyParsingError> {
+    s.parse::<u64>().map_err(|_| MonkeyParsingError::with(s))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MonkeyParsingError {
+    value: String,
+}
+
+impl MonkeyParsingError {
+    fn with(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Display for MonkeyParsingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid monkey description: {}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_test_input, AocError, Solution};
+
+    use crate::{rounds, Day11, Monkey, MonkeyParsingError};
+
+    const TEST_FILE: &str = "inputs/test_input.txt";
+
+    fn test_transform(s: String) -> Vec<Monkey> {
+        s.split("\n\n")
+            .map(|s| s.parse::<Monkey>().unwrap())
+            .collect::<_>()
+    }
+
+    #[test]
+    fn can_read_input() {
+        let input: Vec<Monkey> = get_test_input(TEST_FILE, |s| {
+            s.split("\n\n")
+                .map(|s| s.parse::<Monkey>().unwrap())
+                .collect::<_>()
+        });
+
+        assert!(input.len() == 4);
+    }
+
+    #[test]
+    fn fail_on_bad_monkey() {
+        let result = "Monkey 0:\n  Starting items: 79, ninety-eight".parse::<Monkey>();
+
+        assert!(result.err() == Some(MonkeyParsingError::with("ninety-eight")));
+    }
+
+    #[test]
+    fn fail_on_monkeys_that_can_not_play() {
+        let monkey = |divisor: u64, to: u64| {
+            format!(
+                "Monkey 0:\n  Starting items: 79\n  Operation: new = old * 19\n  \
+                 Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                 If false: throw to monkey 0",
+                divisor, to
+            )
+        };
+        let line = |input: String| match Day11::parse(input) {
+            Err(AocError::Transform { line, .. }) => Some(line),
+            _ => None,
+        };
+
+        assert!(line(monkey(23, 1)) == Some(1));
+        assert!(line(format!("{}\n\n{}", monkey(23, 1), monkey(23, 2))) == Some(8));
+        assert!(line(format!("{}\n\n{}", monkey(0, 1), monkey(23, 0))) == Some(1));
+        assert!(line(format!("{}\n\n{}", monkey(23, 1), monkey(23, 0))).is_none());
+    }
+
+    #[test]
+    fn can_monkey_round() {
+        let mut monkeys = get_test_input(TEST_FILE, test_transform);
+
+        let divisor_product = monkeys.iter().map(|m| m.test.divisible_by).product::<u64>();
+
+        let new_monkeys = rounds(20, &mut monkeys, true, divisor_product);
+
+        assert!(new_monkeys.len() == 4);
+    }
+
+    #[test]
+    fn can_get_most_active_monkeys() {
+        let mut monkeys = get_test_input(TEST_FILE, test_transform);
+        let divisor_product = monkeys.iter().map(|m| m.test.divisible_by).product::<u64>();
+        let mut new_monkeys = rounds(20, &mut monkeys, true, divisor_product);
+
+        new_monkeys.sort_by_key(|m| m.inpsected_count());
+
+        let top_two = new_monkeys.iter().rev().take(2).collect::<Vec<&Monkey>>();
+
+        let monkey_business = top_two[0].inpsected_count() * top_two[1].inpsected_count();
+
+        assert!(monkey_business == 10605);
+    }
+
+    #[test]
+    fn can_get_most_active_monkeys_no_worry_reduction() {
+        let mut monkeys = get_test_input(TEST_FILE, test_transform);
+        let divisor_product = monkeys.iter().map(|m| m.test.divisible_by).product::<u64>();
+        let mut new_monkeys = rounds(10_000, &mut monkeys, false, divisor_product);
+
+        new_monkeys.sort_by_key(|m| m.inpsected_count());
+
+        let top_two = new_monkeys.iter().rev().take(2).collect::<Vec<&Monkey>>();
+
+        let monkey_business = top_two[0].inpsected_count() * top_two[1].inpsected_count();
+
+        assert!(monkey_business == 2713310158);
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt::Display;
+
+use aoc_common::{bfs, AocError, Grid, Puzzle, Registry, Solution};
+
+/// Got stuck on this one, but found
+/// [this](https://github.com/NickyMeuleman/scrapyard/blob/main/advent_of_code/2022/src/day_12.rs)
+/// very helpful code (the first version used it, now it is a plain [`bfs`])
+pub struct Day12;
+
+impl Solution for Day12 {
+    const PUZZLE: Puzzle = Puzzle::new(2022, 12);
+    type Input = HeightMap;
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        let letters = Grid::parse(Self::PUZZLE, &input, |c| {
+            matches!(c, 'a'..='z' | 'S' | 'E').then_some(c)
+        })?;
+        let missing = |letter| AocError::Transform {
+            puzzle: Self::PUZZLE,
+            line: 1,
+            message: format!("no {letter} on the height map"),
+        };
+
+        Ok(HeightMap {
+            start: letters.find(|&c| c == 'S').ok_or_else(|| missing('S'))?,
+            end: letters.find(|&c| c == 'E').ok_or_else(|| missing('E'))?,
+            heights: letters.map(|&c| match c {
+                'S' => 0,
+                'E' => b'z' - b'a',
+                c => c as u8 - b'a',
+            }),
+        })
+    }
+
+    fn part1(map: &Self::Input) -> impl Display {
+        map.fewest_steps([map.start])
+            .map_or_else(|| "no path found".to_string(), |steps| steps.to_string())
+    }
+
+    fn part2(map: &Self::Input) -> impl Display {
+        let lowest = map.heights.iter().filter(|(_, &height)| height == 0);
+
+        map.fewest_steps(lowest.map(|(pos, _)| pos))
+            .map_or_else(|| "no path found".to_string(), |steps| steps.to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day12>();
+}
+
+type Coord = (usize, usize);
+
+#[derive(Debug)]
+pub struct HeightMap {
+    heights: Grid<u8>,
+    start: Coord,
+    end: Coord,
+}
+
+impl HeightMap {
+    /// The fewest steps from any of `starts` to the end, climbing at most one
+    /// higher with every step
+    fn fewest_steps(&self, starts: impl IntoIterator<Item = Coord>) -> Option<usize> {
+        let climbable = |&from: &Coord| {
+            self.heights
+                .neighbours(from)
+                .filter(move |&to| self.heights[to] <= self.heights[from] + 1)
+        };
+
+        bfs(starts, climbable, |&pos| pos == self.end)
+            .found
+            .map(|(steps, _)| steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{AocError, Solution};
+
+    use crate::Day12;
+
+    #[test]
+    fn can_answer_part_1() {
+        let data = Day12::parse(include_str!("../inputs/test_input.txt").to_string()).unwrap();
+
+        dbg!(&data);
+        assert!(data.fewest_steps([data.start]) == Some(31));
+    }
+
+    #[test]
+    fn can_answer_part_2() {
+        let data = Day12::parse(include_str!("../inputs/test_input.txt").to_string()).unwrap();
+
+        dbg!(&data);
+        assert!(Day12::part2(&data).to_string() == "29");
+    }
+
+    #[test]
+    fn fail_on_bad_height_map() {
+        let bad = Day12::parse("Sab\nc#E\n".to_string());
+        let no_end = Day12::parse("Sab\n".to_string());
+
+        assert!(matches!(bad, Err(AocError::Transform { line: 2, .. })));
+        assert!(matches!(no_end, Err(AocError::Transform { .. })));
+    }
+}
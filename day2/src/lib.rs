@@ -0,0 +1,219 @@
+use std::{fmt::Display, str::FromStr};
+
+use aoc_common::{transform_lines, AocError, Puzzle, Registry, Solution};
+
+use crate::Rps::{Paper, Rock, Scissors};
+use crate::Wld::{Draw, Lose, Win};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const PUZZLE: Puzzle = Puzzle::new(2022, 2);
+    type Input = (Vec<(Rps, Rps)>, Vec<(Rps, Wld)>);
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        Ok((
+            transform_lines(Self::PUZZLE, &input, read_round::<Rps, Rps>)?,
+            transform_lines(Self::PUZZLE, &input, read_round::<Rps, Wld>)?,
+        ))
+    }
+
+    fn part1((rounds, _): &Self::Input) -> impl Display {
+        rounds.iter().map(get_score).sum::<i32>()
+    }
+
+    fn part2((_, rounds): &Self::Input) -> impl Display {
+        rounds
+            .iter()
+            .map(|round| (round.0, round.1.convert(&round.0)))
+            .map(|round| get_score(&round))
+            .sum::<i32>()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day2>();
+}
+
+fn read_round<L, R>(line: String) -> Result<(L, R), ReadError>
+where
+    L: FromStr<Err = ReadError>,
+    R: FromStr<Err = ReadError>,
+{
+    let (left, right) = line.split_once(' ').ok_or_else(|| ReadError::with(&line))?;
+    Ok((left.parse()?, right.parse()?))
+}
+
+fn get_score_for_shape(round: &(Rps, Rps)) -> i32 {
+    match round.1 {
+        Rock => 1,
+        Paper => 2,
+        Scissors => 3,
+    }
+}
+
+fn get_score_for_outcome(round: &(Rps, Rps)) -> i32 {
+    match round {
+        (Scissors, Rock) | (Paper, Scissors) | (Rock, Paper) => 6,
+        (Rock, Rock) | (Paper, Paper) | (Scissors, Scissors) => 3,
+        (Rock, Scissors) | (Scissors, Paper) | (Paper, Rock) => 0,
+    }
+}
+
+fn get_score(round: &(Rps, Rps)) -> i32 {
+    get_score_for_shape(round) + get_score_for_outcome(round)
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Rps {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl FromStr for Rps {
+    type Err = ReadError;
+
+    fn from_str(c: &str) -> Result<Self, Self::Err> {
+        match c {
+            "A" | "X" => Ok(Rock),
+            "B" | "Y" => Ok(Paper),
+            "C" | "Z" => Ok(Scissors),
+            _ => Err(ReadError::with(c)),
+        }
+    }
+}
+
+#[derive(PartialEq)]
+pub enum Wld {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl FromStr for Wld {
+    type Err = ReadError;
+
+    fn from_str(c: &str) -> Result<Self, Self::Err> {
+        match c {
+            "X" => Ok(Lose),
+            "Y" => Ok(Draw),
+            "Z" => Ok(Win),
+            _ => Err(ReadError::with(c)),
+        }
+    }
+}
+
+impl Wld {
+    fn convert(&self, other: &Rps) -> Rps {
+        match self {
+            Self::Lose => match other {
+                Rock => Scissors,
+                Paper => Rock,
+                Scissors => Paper,
+            },
+            Self::Draw => *other,
+            Self::Win => match other {
+                Rock => Paper,
+                Paper => Scissors,
+                Scissors => Rock,
+            },
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ReadError {
+    value: String,
+}
+
+impl ReadError {
+    fn with(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid strategy guide entry: {}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::get_test_input;
+
+    use crate::{
+        get_score, read_round, ReadError,
+        Rps::{self, Paper, Rock, Scissors},
+        Wld::{self, Draw, Lose, Win},
+    };
+
+    const TEST_INPUT_FILE: &str = "inputs/test_input.txt";
+
+    fn test_transform_rps(s: String) -> Vec<(Rps, Rps)> {
+        s.split('\n')
+            .map(|s| s.split(' ').collect::<Vec<&str>>())
+            .map(|cs| {
+                assert!(cs.len() == 2);
+                (cs[0].parse().unwrap(), cs[1].parse().unwrap())
+            })
+            .collect::<Vec<(Rps, Rps)>>()
+    }
+
+    fn test_transform_wld(s: String) -> Vec<(Rps, Wld)> {
+        s.split('\n')
+            .map(|s| s.split(' ').collect::<Vec<&str>>())
+            .map(|v| {
+                assert!(v.len() == 2);
+                (v[0].parse().unwrap(), v[1].parse().unwrap())
+            })
+            .collect::<Vec<(Rps, Wld)>>()
+    }
+
+    #[test]
+    fn can_read_rps_input() {
+        let input: Vec<(Rps, Rps)> = get_test_input(TEST_INPUT_FILE, test_transform_rps);
+        assert!(input == vec![(Rock, Paper), (Paper, Rock), (Scissors, Scissors)])
+    }
+
+    #[test]
+    fn can_read_wld_input() {
+        let input: Vec<(Rps, Wld)> = get_test_input(TEST_INPUT_FILE, test_transform_wld);
+        assert!(input == vec![(Rock, Draw), (Paper, Lose), (Scissors, Win)]);
+    }
+
+    #[test]
+    fn fail_on_bad_rounds() {
+        let result = read_round::<Rps, Wld>("A Q".to_string());
+        assert!(result.err() == Some(ReadError::with("Q")));
+
+        let result = read_round::<Rps, Rps>("AX".to_string());
+        assert!(result.err() == Some(ReadError::with("AX")));
+    }
+
+    #[test]
+    fn can_get_scores() {
+        let input: Vec<(Rps, Rps)> = get_test_input(TEST_INPUT_FILE, test_transform_rps);
+        let scores = input.iter().map(get_score).collect::<Vec<i32>>();
+
+        assert!(scores == vec![8, 1, 6]);
+        assert!(scores.iter().sum::<i32>() == 15);
+    }
+
+    #[test]
+    fn can_decrypt_rounds_with_wld_to_rps() {
+        let input: Vec<(Rps, Wld)> = get_test_input(TEST_INPUT_FILE, test_transform_wld);
+
+        let scores = input
+            .iter()
+            .map(|round| (round.0, round.1.convert(&round.0)))
+            .map(|round| get_score(&round))
+            .collect::<Vec<i32>>();
+
+        assert!(scores == vec![4, 1, 7]);
+        assert!(scores.iter().sum::<i32>() == 12);
+    }
+}
@@ -0,0 +1,219 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use aoc_common::{transform_lines, AocError, Puzzle, Registry, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const PUZZLE: Puzzle = Puzzle::new(2022, 3);
+    type Input = Rucksacks;
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        let rucksacks = transform_lines(Self::PUZZLE, &input, read_rucksack)?;
+
+        let badges = rucksacks
+            .chunks(3)
+            .enumerate()
+            .map(|(idx, group)| {
+                let items = group
+                    .iter()
+                    .map(|(items, _)| items.clone())
+                    .collect::<Vec<_>>();
+                find_badge(&items)
+                    .and_then(|c| priority(&c))
+                    .ok_or_else(|| AocError::Transform {
+                        puzzle: Self::PUZZLE,
+                        line: idx * 3 + 1,
+                        message: "Expected a group of three rucksacks sharing one item".to_string(),
+                    })
+            })
+            .collect::<Result<Vec<usize>, _>>()?;
+
+        Ok(Rucksacks {
+            shared: rucksacks.into_iter().map(|(_, shared)| shared).collect(),
+            badges,
+        })
+    }
+
+    fn part1(rucksacks: &Self::Input) -> impl Display {
+        rucksacks.shared.iter().sum::<usize>()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> impl Display {
+        rucksacks.badges.iter().sum::<usize>()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day3>();
+}
+
+/// The priorities of the item in both compartments of every rucksack and of
+/// the badge of every group of three
+pub struct Rucksacks {
+    shared: Vec<usize>,
+    badges: Vec<usize>,
+}
+
+/// A rucksack with the priority of the item in both of its compartments
+fn read_rucksack(line: String) -> Result<(String, usize), RucksackError> {
+    if !line.len().is_multiple_of(2) || !line.chars().all(|c| priority(&c).is_some()) {
+        return Err(RucksackError::with(&line));
+    }
+    let shared = diff(&compartments(&line)).and_then(|c| priority(&c));
+    shared
+        .map(|shared| (line.clone(), shared))
+        .ok_or_else(|| RucksackError::with(&line))
+}
+
+fn compartments(s: &str) -> (Vec<char>, Vec<char>) {
+    let half = s.len() / 2;
+    (
+        s.chars().take(half).collect(),
+        s.chars().rev().take(half).collect(),
+    )
+}
+
+/// The item in both compartments
+fn diff((s1, s2): &(Vec<char>, Vec<char>)) -> Option<char> {
+    s1.iter().find(|c| s2.contains(c)).copied()
+}
+
+const LOWERCASE: RangeInclusive<char> = 'a'..='z';
+const UPPERCASE: RangeInclusive<char> = 'A'..='Z';
+
+/// `None` for anything but a letter
+fn priority(c: &char) -> Option<usize> {
+    for (idx, ch) in LOWERCASE.enumerate() {
+        if *c == ch {
+            return Some(idx + 1);
+        }
+    }
+    for (idx, ch) in UPPERCASE.enumerate() {
+        if *c == ch {
+            return Some(idx + 27);
+        }
+    }
+    None
+}
+
+/// The item all three rucksacks of a group carry
+fn find_badge(s: &[String]) -> Option<char> {
+    let [first, second, third] = s else {
+        return None;
+    };
+
+    first
+        .chars()
+        .find(|&c| second.contains(c) && third.contains(c))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RucksackError {
+    value: String,
+}
+
+impl RucksackError {
+    fn with(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Display for RucksackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid rucksack: {}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_test_input, AocError, Solution};
+
+    use crate::{diff, find_badge, priority, Day3};
+
+    const TEST_FILE: &str = "inputs/test_input.txt";
+
+    fn create_rucksack_compartments(s: String) -> Vec<(Vec<char>, Vec<char>)> {
+        s.split('\n')
+            .map(|s| {
+                let half = s.len() / 2;
+                (
+                    s.chars().take(half).collect(),
+                    s.chars().rev().take(half).collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn create_elf_groups(s: String) -> Vec<Vec<String>> {
+        let strings: Vec<String> = s.split('\n').map(|s| s.to_string()).collect();
+        let mut out_vec: Vec<Vec<String>> = vec![];
+        for chunk in strings.chunks_exact(3) {
+            let mut inner_vec: Vec<String> = vec![];
+            for s in chunk {
+                inner_vec.push(s.to_string());
+            }
+            out_vec.push(inner_vec);
+        }
+        out_vec
+    }
+
+    #[test]
+    fn can_get_input() {
+        let input = get_test_input(TEST_FILE, create_rucksack_compartments);
+
+        assert!(input.len() == 6);
+    }
+
+    #[test]
+    fn can_diff_compartments() {
+        let shared_items: Vec<char> = get_test_input(TEST_FILE, create_rucksack_compartments)
+            .iter()
+            .map(|r| diff(r).unwrap())
+            .collect();
+
+        assert!(shared_items == vec!['p', 'L', 'P', 'v', 't', 's']);
+    }
+
+    #[test]
+    fn can_get_priority() {
+        let priorities: Vec<usize> = get_test_input(TEST_FILE, create_rucksack_compartments)
+            .iter()
+            .map(|r| diff(r).and_then(|c| priority(&c)).unwrap())
+            .collect();
+
+        assert!(priorities == vec![16, 38, 42, 22, 20, 19]);
+        assert!(priorities.iter().sum::<usize>() == 157);
+    }
+
+    #[test]
+    fn can_find_badges() {
+        let badges: Vec<char> = get_test_input(TEST_FILE, create_elf_groups)
+            .iter()
+            .map(|s| find_badge(s).unwrap())
+            .collect();
+
+        assert!(badges == vec!['r', 'Z']);
+
+        let priority_sum = badges.iter().map(|c| priority(c).unwrap()).sum::<usize>();
+
+        assert!(priority_sum == 70);
+    }
+
+    #[test]
+    fn fail_on_bad_rucksacks() {
+        let line = |input: &str| match Day3::parse(input.to_string()) {
+            Err(AocError::Transform { line, .. }) => Some(line),
+            _ => None,
+        };
+
+        assert!(line("abca\nabcde\nabca") == Some(2));
+        assert!(line("abca\nab1a\nabca") == Some(2));
+        assert!(line("abca\nabcd\nabca") == Some(2));
+        assert!(line("abca\naxya\nbzyb") == Some(1));
+        assert!(line("abca\naxya\nazya\nabca") == Some(4));
+        assert!(line("abca\naxya\nazya").is_none());
+    }
+}
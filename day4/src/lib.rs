@@ -0,0 +1,132 @@
+use std::fmt::Display;
+
+use aoc_common::{transform_lines, AocError, Puzzle, Registry, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const PUZZLE: Puzzle = Puzzle::new(2022, 4);
+    type Input = Vec<SectionAssignment>;
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        transform_lines(Self::PUZZLE, &input, read_assignment)
+    }
+
+    fn part1(section_assignments: &Self::Input) -> impl Display {
+        section_assignments
+            .iter()
+            .filter(|&ap| complete_overlap(ap))
+            .count()
+    }
+
+    fn part2(section_assignments: &Self::Input) -> impl Display {
+        section_assignments
+            .iter()
+            .filter(|&ap| any_overlap(ap))
+            .count()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day4>();
+}
+
+pub type SectionAssignment = ((usize, usize), (usize, usize));
+
+/// A line like `2-4,6-8`
+fn read_assignment(line: String) -> Result<SectionAssignment, AssignmentError> {
+    let range = |range: &str| {
+        let (start, end) = range.split_once('-')?;
+        Some((start.parse().ok()?, end.parse().ok()?))
+    };
+    line.split_once(',')
+        .and_then(|(first, second)| Some((range(first)?, range(second)?)))
+        .ok_or_else(|| AssignmentError::with(&line))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AssignmentError {
+    value: String,
+}
+
+impl AssignmentError {
+    fn with(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Display for AssignmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid section assignment: {}", self.value)
+    }
+}
+
+fn complete_overlap(((r1_start, r1_end), (r2_start, r2_end)): &SectionAssignment) -> bool {
+    r1_start <= r2_start && r1_end >= r2_end || r2_start <= r1_start && r2_end >= r1_end
+}
+
+fn any_overlap(((r1_start, r1_end), (r2_start, r2_end)): &SectionAssignment) -> bool {
+    for n in *r1_start..=*r1_end {
+        if (*r2_start..=*r2_end).contains(&n) {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+
+    use aoc_common::{get_test_input, Solution};
+
+    use crate::{
+        any_overlap, complete_overlap, read_assignment, AssignmentError, Day4, SectionAssignment,
+    };
+
+    const TEST_FILE: &str = "inputs/test_input.txt";
+
+    #[test]
+    fn can_get_input() {
+        let section_assignments: Vec<SectionAssignment> =
+            get_test_input(TEST_FILE, Day4::parse).unwrap();
+
+        assert!(section_assignments.len() == 6);
+    }
+
+    #[test]
+    fn fail_on_bad_assignment() {
+        assert!(read_assignment("2-4,6-8".to_string()) == Ok(((2, 4), (6, 8))));
+        assert!(read_assignment("2-4;6-8".to_string()) == Err(AssignmentError::with("2-4;6-8")));
+        assert!(read_assignment("2-x,6-8".to_string()).is_err());
+    }
+
+    #[test]
+    fn can_find_complete_overlaps() {
+        let section_assignments: Vec<SectionAssignment> =
+            get_test_input(TEST_FILE, Day4::parse).unwrap();
+
+        assert!(
+            section_assignments
+                .iter()
+                .filter(|&ap| complete_overlap(ap))
+                .count()
+                == 2
+        );
+    }
+
+    #[test]
+    fn can_find_any_overlaps() {
+        let section_assignments: Vec<SectionAssignment> =
+            get_test_input(TEST_FILE, Day4::parse).unwrap();
+
+        assert!(
+            section_assignments
+                .iter()
+                .filter(|&ap| any_overlap(ap))
+                .count()
+                == 4
+        );
+    }
+}
@@ -0,0 +1,415 @@
+use std::{fmt::Display, str::FromStr};
+
+use aoc_common::{transform_lines, AocError, Puzzle, Registry, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const PUZZLE: Puzzle = Puzzle::new(2022, 5);
+    type Input = (Vec<(usize, Stack)>, Moves);
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        let (drawing, moves) =
+            input
+                .trim_end()
+                .split_once("\n\n")
+                .ok_or_else(|| AocError::Transform {
+                    puzzle: Self::PUZZLE,
+                    line: input.lines().count(),
+                    message: "Expected a blank line between the stacks and the moves".to_string(),
+                })?;
+        let stacks = get_stacks(drawing)?;
+        let moves = get_moves(moves, &stacks, drawing.lines().count() + 1)?;
+        Ok((stacks, moves))
+    }
+
+    fn part1((stacks, moves): &Self::Input) -> impl Display {
+        let mut stacks = stacks.clone();
+
+        for mv in moves {
+            stacks = do_move(stacks, *mv);
+        }
+
+        tops(&stacks)
+    }
+
+    fn part2((stacks, moves): &Self::Input) -> impl Display {
+        let mut stacks = stacks.clone();
+
+        for mv in moves {
+            stacks = do_move_part_2(stacks, *mv);
+        }
+
+        tops(&stacks)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day5>();
+}
+
+/// The crate on top of every stack, a space for an empty one
+fn tops(stacks: &[(usize, Stack)]) -> String {
+    stacks
+        .iter()
+        .map(|stack| stack.1.last().map_or(" ".to_string(), |t| t.to_string()))
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+/// The drawing of the stacks, the last line numbers them
+fn get_stacks(drawing: &str) -> Result<Vec<(usize, Stack)>, AocError> {
+    let (rows, numbers) = drawing.rsplit_once('\n').unwrap_or(("", drawing));
+    let count = numbers.split_whitespace().count();
+    let rows = transform_lines(Day5::PUZZLE, rows, |line| read_crates(&line, count))?;
+
+    let mut stacks = vec![Stack::new(); count];
+    for row in rows.iter().rev() {
+        for (stack, c) in stacks.iter_mut().zip(row) {
+            stack.extend(c);
+        }
+    }
+    Ok(stacks.into_iter().enumerate().collect())
+}
+
+/// One row of the drawing, a crate like `[A]` or three spaces every four
+/// characters
+fn read_crates(line: &str, count: usize) -> Result<Vec<Option<Crate>>, CrateError> {
+    let row = line
+        .chars()
+        .collect::<Vec<char>>()
+        .chunks(4)
+        .map(
+            |chunk| match chunk.iter().take(3).collect::<String>().trim() {
+                "" => Some(None),
+                name => Crate::from(name).map(Some),
+            },
+        )
+        .collect::<Option<Vec<_>>>()
+        .filter(|row| row.len() <= count);
+    row.ok_or_else(|| CrateError::with(line))
+}
+
+/// `first_line` is the line number of the first move in the whole input,
+/// the moves are played through to check no stack runs out of crates
+fn get_moves(
+    move_string: &str,
+    stacks: &[(usize, Stack)],
+    first_line: usize,
+) -> Result<Moves, AocError> {
+    let count = stacks.len();
+    let moves = transform_lines(Day5::PUZZLE, move_string, |line| {
+        let mv = line.parse::<Move>()?;
+        if mv.from >= count || mv.to >= count || mv.from == mv.to {
+            return Err(MoveError::with(&line));
+        }
+        Ok(mv)
+    })
+    .map_err(|e| match e {
+        AocError::Transform {
+            puzzle,
+            line,
+            message,
+        } => AocError::Transform {
+            puzzle,
+            line: line + first_line,
+            message,
+        },
+        e => e,
+    })?;
+
+    let mut heights = stacks.iter().map(|stack| stack.1.len()).collect::<Vec<_>>();
+    for (idx, mv) in moves.iter().enumerate() {
+        if mv.how_many > heights[mv.from] {
+            return Err(AocError::Transform {
+                puzzle: Day5::PUZZLE,
+                line: first_line + idx + 1,
+                message: format!(
+                    "Can not move {} crates, stack {} only holds {}",
+                    mv.how_many,
+                    mv.from + 1,
+                    heights[mv.from]
+                ),
+            });
+        }
+        heights[mv.from] -= mv.how_many;
+        heights[mv.to] += mv.how_many;
+    }
+    Ok(moves)
+}
+
+fn do_move(stacks: Vec<(usize, Stack)>, mv: Move) -> Vec<(usize, Stack)> {
+    let mut from_stack = stacks[mv.from].clone();
+    let mut to_stack = stacks[mv.to].clone();
+    let mut others: Vec<(usize, Stack)> = vec![];
+
+    let moved = from_stack.1.split_off(from_stack.1.len() - mv.how_many);
+    to_stack.1.extend(moved.into_iter().rev());
+
+    for (i, stack) in stacks.iter().enumerate() {
+        if i != mv.from && i != mv.to {
+            others.push(stack.clone());
+        }
+    }
+    let mut result = [vec![from_stack], vec![to_stack], others].concat();
+    result.sort_by_key(|a| a.0);
+    result
+}
+
+fn do_move_part_2(stacks: Vec<(usize, Stack)>, mv: Move) -> Vec<(usize, Stack)> {
+    let mut from_stack = stacks[mv.from].clone();
+    let mut to_stack = stacks[mv.to].clone();
+    let mut others: Vec<(usize, Stack)> = vec![];
+
+    let moved = from_stack.1.split_off(from_stack.1.len() - mv.how_many);
+    to_stack.1.extend(moved);
+
+    for (i, stack) in stacks.iter().enumerate() {
+        if i != mv.from && i != mv.to {
+            others.push(stack.clone());
+        }
+    }
+    let mut result = [vec![from_stack], vec![to_stack], others].concat();
+    result.sort_by_key(|a| a.0);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read_to_string;
+
+    use aoc_common::{get_test_input, AocError, Solution};
+
+    use crate::{do_move, do_move_part_2, get_stacks, tops, Crate, Day5};
+
+    const TEST_FILE: &str = "inputs/test_input.txt";
+
+    #[test]
+    fn can_read_input() {
+        let (stacks, moves) = get_test_input(TEST_FILE, Day5::parse).unwrap();
+
+        assert!(stacks.len() == 9 && moves.len() == 2);
+    }
+
+    #[test]
+    fn can_parse_stacks() {
+        let s = read_to_string(TEST_FILE).unwrap();
+        let (drawing, _) = s.split_once("\n\n").unwrap();
+        let stacks = get_stacks(drawing).unwrap();
+
+        assert!(stacks[0].1[4] == Crate::R);
+        assert!(stacks[4].1.last().unwrap() == &Crate::M);
+        assert!(stacks[6].1.len() == 3);
+        assert!(stacks[8].1.last().unwrap() == &Crate::L);
+    }
+
+    #[test]
+    fn fail_on_bad_drawings_and_moves() {
+        let parse = |input: &str| match Day5::parse(input.to_string()) {
+            Err(AocError::Transform { line, .. }) => Some(line),
+            _ => None,
+        };
+
+        assert!(parse("[A] [B]\n[C] [x]\n 1   2\n\nmove 1 from 1 to 2") == Some(2));
+        assert!(parse("[A] [B] [C]\n 1   2\n\nmove 1 from 1 to 2") == Some(1));
+        assert!(parse("[A] [B]\n 1   2\n\nmove 1 from 1 to 2\nmove 1 from 2 to 3") == Some(5));
+        assert!(parse("[A] [B]\n 1   2\n\nmove 1 from 0 to 2") == Some(4));
+        assert!(parse("[A] [B]\n 1   2\nmove 1 from 1 to 2") == Some(3));
+        assert!(parse("[A] [B]\n 1   2\n\nmove 1 from 1 to 2\nmove 3 from 2 to 1") == Some(5));
+        assert!(parse("[A] [B]\n 1   2\n\nmove 1 from 1 to 2\nmove 2 from 2 to 1").is_none());
+    }
+
+    #[test]
+    fn can_move_stacks() {
+        let (mut stacks, moves) = get_test_input(TEST_FILE, Day5::parse).unwrap();
+
+        for mv in moves {
+            stacks = do_move(stacks, mv);
+        }
+
+        assert!(stacks[2].1.is_empty());
+        assert!(stacks[7].1.len() == 3);
+        assert!(tops(&stacks).starts_with("RB L"));
+    }
+
+    #[test]
+    fn can_move_stacks_part_2() {
+        let (mut stacks, moves) = get_test_input(TEST_FILE, Day5::parse).unwrap();
+
+        for mv in moves {
+            stacks = do_move_part_2(stacks, mv);
+        }
+
+        assert!(stacks[2].1.is_empty());
+        assert!(stacks[7].1.len() == 3);
+        assert!(tops(&stacks).starts_with("RB L"));
+    }
+}
+
+pub type Stack = Vec<Crate>;
+pub type Moves = Vec<Move>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+    how_many: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    fn from(how_many: usize, from: usize, to: usize) -> Self {
+        Self { how_many, from, to }
+    }
+}
+
+/// `move 1 from 2 to 1`, the stacks counting from 1
+impl FromStr for Move {
+    type Err = MoveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.strip_prefix("move ").and_then(|rest| {
+            let (how_many, rest) = rest.split_once(" from ")?;
+            let (from, to) = rest.split_once(" to ")?;
+            let stack = |n: &str| n.parse::<usize>().ok()?.checked_sub(1);
+            Some((how_many.parse().ok()?, stack(from)?, stack(to)?))
+        });
+        let (how_many, from, to) = parts.ok_or_else(|| MoveError::with(s))?;
+        Ok(Self::from(how_many, from, to))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Crate {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Crate {
+    fn from(s: &str) -> Option<Self> {
+        let c = match s {
+            "[A]" => Self::A,
+            "[B]" => Self::B,
+            "[C]" => Self::C,
+            "[D]" => Self::D,
+            "[E]" => Self::E,
+            "[F]" => Self::F,
+            "[G]" => Self::G,
+            "[H]" => Self::H,
+            "[I]" => Self::I,
+            "[J]" => Self::J,
+            "[K]" => Self::K,
+            "[L]" => Self::L,
+            "[M]" => Self::M,
+            "[N]" => Self::N,
+            "[O]" => Self::O,
+            "[P]" => Self::P,
+            "[Q]" => Self::Q,
+            "[R]" => Self::R,
+            "[S]" => Self::S,
+            "[T]" => Self::T,
+            "[U]" => Self::U,
+            "[V]" => Self::V,
+            "[W]" => Self::W,
+            "[X]" => Self::X,
+            "[Y]" => Self::Y,
+            "[Z]" => Self::Z,
+            _ => return None,
+        };
+        Some(c)
+    }
+}
+
+impl Display for Crate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::A => write!(f, "A"),
+            Self::B => write!(f, "B"),
+            Self::C => write!(f, "C"),
+            Self::D => write!(f, "D"),
+            Self::E => write!(f, "E"),
+            Self::F => write!(f, "F"),
+            Self::G => write!(f, "G"),
+            Self::H => write!(f, "H"),
+            Self::I => write!(f, "I"),
+            Self::J => write!(f, "J"),
+            Self::K => write!(f, "K"),
+            Self::L => write!(f, "L"),
+            Self::M => write!(f, "M"),
+            Self::N => write!(f, "N"),
+            Self::O => write!(f, "O"),
+            Self::P => write!(f, "P"),
+            Self::Q => write!(f, "Q"),
+            Self::R => write!(f, "R"),
+            Self::S => write!(f, "S"),
+            Self::T => write!(f, "T"),
+            Self::U => write!(f, "U"),
+            Self::V => write!(f, "V"),
+            Self::W => write!(f, "W"),
+            Self::X => write!(f, "X"),
+            Self::Y => write!(f, "Y"),
+            Self::Z => write!(f, "Z"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CrateError {
+    value: String,
+}
+
+impl CrateError {
+    fn with(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Display for CrateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid row of crates: {}", self.value)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MoveError {
+    value: String,
+}
+
+impl MoveError {
+    fn with(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid move: {}", self.value)
+    }
+}
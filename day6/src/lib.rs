@@ -0,0 +1,85 @@
+use std::fmt::Display;
+
+use aoc_common::{AocError, Puzzle, Registry, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const PUZZLE: Puzzle = Puzzle::new(2022, 6);
+    type Input = String;
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let (_marker, count) = find_marker(input, 4);
+        count
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let (_marker, count) = find_marker(input, 14);
+        count
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day6>();
+}
+
+fn find_marker(s: &str, size: usize) -> (String, i32) {
+    let mut count = 0;
+    let mut marker = "".to_string();
+
+    for c in s.chars() {
+        marker = format!("{}{}", marker, c);
+        if marker.len() == size {
+            if is_unique(&marker) {
+                break;
+            } else {
+                marker = marker.chars().collect::<Vec<char>>()[1..].iter().collect();
+            }
+        }
+        count += 1;
+    }
+    (marker, count + 1)
+}
+
+fn is_unique(s: &str) -> bool {
+    let mut letters: Vec<char> = vec![];
+    for c in s.chars() {
+        if letters.contains(&c) {
+            return false;
+        } else {
+            letters.push(c);
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::get_test_input;
+
+    use crate::find_marker;
+
+    fn transform(s: String) -> String {
+        s
+    }
+
+    #[test]
+    fn can_read_input() {
+        let input = get_test_input("inputs/test_input.txt", transform);
+
+        assert!(!input.is_empty());
+    }
+
+    #[test]
+    fn can_find_marker() {
+        let input = get_test_input("inputs/test_input.txt", transform);
+        let (marker, count) = find_marker(&input, 4);
+
+        assert!(marker == "jpqm");
+        assert!(count == 7);
+    }
+}
@@ -0,0 +1,451 @@
+mod scratch;
+
+use core::fmt;
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+use aoc_common::{fetch_with_transform, AocError, Puzzle, Registry, Solution};
+use camino::Utf8PathBuf;
+use indexmap::IndexMap;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    combinator::{all_consuming, map},
+    sequence::{preceded, separated_pair},
+    Finish, IResult,
+};
+
+use crate::scratch::{apply_command, apply_command_to_directories, transform, Directory};
+
+/// needed help on this one - see [this answer](https://fasterthanli.me/series/advent-of-code-2022/part-7) for more!
+pub struct Day7;
+
+impl Solution for Day7 {
+    const PUZZLE: Puzzle = Puzzle::new(2022, 7);
+    type Input = NodeHandle;
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        let lines = input
+            .lines()
+            .enumerate()
+            .map(|(idx, l)| {
+                all_consuming(parse_line)(l)
+                    .finish()
+                    .map(|(_, line)| line)
+                    .map_err(|e| AocError::Transform {
+                        puzzle: Self::PUZZLE,
+                        line: idx + 1,
+                        message: e.to_string(),
+                    })
+            })
+            .collect::<Result<Vec<Line>, _>>()?;
+
+        let root = Rc::new(RefCell::new(Node::default()));
+        let mut node = root.clone();
+
+        for (idx, line) in lines.into_iter().enumerate() {
+            match line {
+                Line::Command(cmd) => match cmd {
+                    Command::Ls => {
+                        //ignored
+                    }
+                    Command::Cd(path) => match path.as_str() {
+                        "/" => {
+                            // ignored
+                        }
+                        ".." => {
+                            let parent = node.borrow().parent.clone().ok_or_else(|| {
+                                AocError::Transform {
+                                    puzzle: Self::PUZZLE,
+                                    line: idx + 1,
+                                    message: "Can not cd .. out of /".to_string(),
+                                }
+                            })?;
+                            node = parent;
+                        }
+                        _ => {
+                            let child = node.borrow_mut().children.entry(path).or_default().clone();
+                            child.borrow_mut().parent = Some(node.clone());
+                            node = child;
+                        }
+                    },
+                },
+                Line::Entry(entry) => match entry {
+                    Entry::Dir(dir) => {
+                        let entry = node.borrow_mut().children.entry(dir).or_default().clone();
+                        entry.borrow_mut().parent = Some(node.clone());
+                    }
+                    Entry::File(size, file) => {
+                        let entry = node.borrow_mut().children.entry(file).or_default().clone();
+                        entry.borrow_mut().size = size as usize;
+                        entry.borrow_mut().parent = Some(node.clone());
+                    }
+                },
+            }
+        }
+        Ok(root)
+    }
+
+    fn part1(root: &Self::Input) -> impl Display {
+        all_dirs(root.clone())
+            .map(|d| d.borrow().total_size())
+            .filter(|&s| s <= 100_000)
+            .sum::<u64>()
+    }
+
+    fn part2(root: &Self::Input) -> impl Display {
+        let total_space = 70000000_u64;
+        let used_space = root.borrow().total_size();
+        let free_space = total_space.saturating_sub(used_space);
+        let needed_free_space = 30000000_u64;
+        let minimum_space_to_free = needed_free_space.saturating_sub(free_space);
+
+        all_dirs(root.clone())
+            .map(|d| d.borrow().total_size())
+            .filter(|&s| s >= minimum_space_to_free)
+            .min()
+            .map_or_else(
+                || "no directory frees up enough space".to_string(),
+                |size| size.to_string(),
+            )
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day7>();
+}
+
+fn parse_path(i: &str) -> IResult<&str, Utf8PathBuf> {
+    map(
+        take_while1(|c: char| "abcdefghijklmnopqrstuvwxyz./".contains(c)),
+        Into::into,
+    )(i)
+}
+
+#[derive(Debug)]
+struct Ls;
+
+fn parse_ls(i: &str) -> IResult<&str, Ls> {
+    map(tag("ls"), |_| Ls)(i)
+}
+
+#[derive(Debug)]
+struct Cd(Utf8PathBuf);
+
+fn parse_cd(i: &str) -> IResult<&str, Cd> {
+    map(preceded(tag("cd "), parse_path), Cd)(i)
+}
+
+#[derive(Debug)]
+enum Command {
+    Ls,
+    Cd(Utf8PathBuf),
+}
+
+impl From<Ls> for Command {
+    fn from(_ls: Ls) -> Self {
+        Command::Ls
+    }
+}
+
+impl From<Cd> for Command {
+    fn from(cd: Cd) -> Self {
+        Self::Cd(cd.0)
+    }
+}
+
+fn parse_command(i: &str) -> IResult<&str, Command> {
+    let (i, _) = tag("$ ")(i)?;
+    alt((map(parse_ls, Into::into), map(parse_cd, Into::into)))(i)
+}
+
+#[derive(Debug)]
+enum Entry {
+    Dir(Utf8PathBuf),
+    File(u64, Utf8PathBuf),
+}
+
+fn parse_entry(i: &str) -> IResult<&str, Entry> {
+    let parse_file = map(
+        separated_pair(nom::character::complete::u64, tag(" "), parse_path),
+        |(size, path)| Entry::File(size, path),
+    );
+    let parse_dir = map(preceded(tag("dir "), parse_path), Entry::Dir);
+
+    alt((parse_file, parse_dir))(i)
+}
+
+#[derive(Debug)]
+enum Line {
+    Command(Command),
+    Entry(Entry),
+}
+
+fn parse_line(i: &str) -> IResult<&str, Line> {
+    alt((
+        map(parse_command, Line::Command),
+        map(parse_entry, Line::Entry),
+    ))(i)
+}
+
+pub type NodeHandle = Rc<RefCell<Node>>;
+
+#[derive(Default, Clone)]
+pub struct Node {
+    size: usize,
+    children: IndexMap<Utf8PathBuf, NodeHandle>,
+    parent: Option<NodeHandle>,
+}
+
+#[allow(dead_code)]
+struct PrettyNode<'a>(&'a NodeHandle);
+
+impl<'a> fmt::Debug for PrettyNode<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let this = self.0.borrow();
+        if this.size == 0 {
+            writeln!(f, "(dir)")?;
+        } else {
+            writeln!(f, "(file, size={})", this.size)?;
+        }
+
+        for (name, child) in &this.children {
+            // not very efficient at all, but shrug
+            for (index, line) in format!("{:?}", PrettyNode(child)).lines().enumerate() {
+                if index == 0 {
+                    writeln!(f, "{name} {line}")?;
+                } else {
+                    writeln!(f, "  {line}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Node {
+    fn is_dir(&self) -> bool {
+        self.size == 0 && !self.children.is_empty()
+    }
+
+    fn total_size(&self) -> u64 {
+        self.children
+            .values()
+            .map(|child| child.borrow().total_size())
+            .sum::<u64>()
+            + self.size as u64
+    }
+}
+
+fn all_dirs(n: NodeHandle) -> Box<dyn Iterator<Item = NodeHandle>> {
+    #[allow(clippy::needless_collect)]
+    let children = n.borrow().children.values().cloned().collect::<Vec<_>>();
+
+    Box::new(
+        std::iter::once(n).chain(
+            children
+                .into_iter()
+                .filter_map(|c| {
+                    if c.borrow().is_dir() {
+                        Some(all_dirs(c))
+                    } else {
+                        None
+                    }
+                })
+                .flatten(),
+        ),
+    )
+}
+
+// This is the work from my own solution that passed the tests (see scratch_tests)
+// but overflows the stack with the actual input.
+fn _scratch_solve() {
+    let mut directories: Vec<Directory> = vec![Directory::new("/")];
+    let commands = fetch_with_transform(7, transform)
+        .iter()
+        .map(|s| crate::scratch::parse_command(s.to_string()))
+        .collect::<Vec<crate::scratch::Command>>();
+
+    let mut current_idx = 0;
+
+    for command in commands {
+        current_idx = apply_command_to_directories(&mut directories, &command, current_idx);
+        apply_command(&mut directories[current_idx], &command)
+    }
+
+    let dir_sizes = directories
+        .iter()
+        .map(|d| d.size(&directories))
+        .filter(|s| s < &100000)
+        .collect::<Vec<i32>>();
+
+    let answer_1 = dir_sizes.iter().sum::<i32>();
+
+    println!("answer 1: {}", answer_1);
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_test_input, AocError, Solution};
+
+    use crate::Day7;
+
+    const TEST_FILE: &str = "inputs/test_input.txt";
+
+    #[test]
+    fn can_find_directories_to_delete() {
+        let root = get_test_input(TEST_FILE, Day7::parse).unwrap();
+
+        assert!(Day7::part1(&root).to_string() == "95437");
+        assert!(Day7::part2(&root).to_string() == "24933642");
+    }
+
+    #[test]
+    fn fail_on_cd_out_of_root() {
+        let result = Day7::parse("$ cd /\n$ cd a\n$ cd ..\n$ cd ..\n".to_string());
+
+        assert!(matches!(result, Err(AocError::Transform { line: 4, .. })));
+    }
+}
+
+#[cfg(test)]
+mod scratch_tests {
+
+    use crate::scratch::{
+        apply_command, apply_command_to_directories, parse_command, transform, Command, Directory,
+        FileType,
+    };
+
+    use aoc_common::get_test_input;
+
+    const TEST_FILE: &str = "inputs/test_input.txt";
+
+    #[test]
+    fn can_read_input() {
+        let command_lines = get_test_input(TEST_FILE, transform);
+
+        assert!(command_lines.len() == 10);
+    }
+
+    #[test]
+    fn can_parse_cd_command() {
+        let command_line = "cd /\n".to_string();
+        let command = parse_command(command_line);
+
+        assert!(command == Command::CD("/".to_string()))
+    }
+
+    #[test]
+    fn can_parse_ls_command() {
+        let command_line = "ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n".to_string();
+        let command = parse_command(command_line);
+
+        assert!(
+            command
+                == Command::LS(vec![
+                    FileType::Dir("e".to_string()),
+                    FileType::File((29116, "f".to_string())),
+                    FileType::File((2557, "g".to_string())),
+                    FileType::File((62596, "h.lst".to_string()))
+                ])
+        );
+    }
+
+    #[test]
+    fn can_create_directory() {
+        let directory = Directory::new("/");
+
+        assert!(directory.name == "/");
+        assert!(directory.directories.is_empty());
+        assert!(directory.files.is_empty());
+    }
+
+    #[test]
+    fn can_apply_commands_to_vec_of_dir() {
+        let mut directories: Vec<Directory> = vec![Directory::new("/")];
+        let command_1 = Command::CD("/".to_string());
+        let command_2 = Command::LS(vec![
+            FileType::Dir("foo".to_string()),
+            FileType::File((29116, "f".to_string())),
+        ]);
+        let command_3 = Command::CD("foo".to_string());
+
+        let mut current_idx = 0;
+
+        current_idx = apply_command_to_directories(&mut directories, &command_1, current_idx);
+
+        assert!(current_idx == 0);
+
+        current_idx = apply_command_to_directories(&mut directories, &command_2, current_idx);
+
+        assert!(current_idx == 0);
+
+        current_idx = apply_command_to_directories(&mut directories, &command_3, current_idx);
+
+        assert!(current_idx == 1);
+    }
+
+    #[test]
+    fn can_apply_command_to_directory() {
+        let mut directory = Directory::new("/");
+        let command_1 = Command::CD("foo".to_string());
+        let command_2 = Command::LS(vec![
+            FileType::Dir("foo".to_string()),
+            FileType::File((29116, "f".to_string())),
+        ]);
+
+        apply_command(&mut directory, &command_1);
+        assert!(directory.directories.is_empty());
+
+        apply_command(&mut directory, &command_2);
+        assert!(directory.directories.contains(&"foo".to_string()));
+    }
+
+    #[test]
+    fn can_apply_commands() {
+        let mut directories: Vec<Directory> = vec![Directory::new("/")];
+        let commands = get_test_input(TEST_FILE, transform)
+            .iter()
+            .map(|s| parse_command(s.to_string()))
+            .collect::<Vec<Command>>();
+
+        let mut current_idx = 0;
+
+        for command in commands {
+            current_idx = apply_command_to_directories(&mut directories, &command, current_idx);
+            apply_command(&mut directories[current_idx], &command)
+        }
+
+        assert!(directories.len() == 5);
+    }
+
+    #[test]
+    fn can_get_empty_directory_size() {
+        let directory = Directory::new("/");
+
+        assert!(directory.size(&vec![]) == 0)
+    }
+
+    #[test]
+    fn can_get_directory_sizes() {
+        let mut directories: Vec<Directory> = vec![Directory::new("/")];
+        let commands = get_test_input(TEST_FILE, transform)
+            .iter()
+            .map(|s| parse_command(s.to_string()))
+            .collect::<Vec<Command>>();
+
+        let mut current_idx = 0;
+
+        for command in commands {
+            current_idx = apply_command_to_directories(&mut directories, &command, current_idx);
+            apply_command(&mut directories[current_idx], &command)
+        }
+
+        let dir_sizes = directories
+            .iter()
+            .map(|d| d.size(&directories))
+            .filter(|s| s < &100000)
+            .collect::<Vec<i32>>();
+
+        assert!(dir_sizes == [94853, 584, 0]);
+    }
+}
@@ -102,7 +102,7 @@ pub fn parse_command(s: String) -> Command {
             .first()
             .unwrap()
             .split(' ')
-            .last()
+            .next_back()
             .unwrap()
             .to_string();
         Command::CD(location)
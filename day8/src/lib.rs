@@ -0,0 +1,97 @@
+use std::fmt::Display;
+
+use aoc_common::{AocError, Direction, Grid, Puzzle, Registry, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const PUZZLE: Puzzle = Puzzle::new(2022, 8);
+    type Input = Grid<u32>;
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        Grid::parse(Self::PUZZLE, &input, |c| c.to_digit(10))
+    }
+
+    fn part1(map: &Self::Input) -> impl Display {
+        visibility(map).iter().filter(|(_, tree)| tree.0).count()
+    }
+
+    fn part2(map: &Self::Input) -> impl Display {
+        visibility(map)
+            .iter()
+            .filter(|(_, tree)| tree.0)
+            .map(|(_, tree)| tree.1)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day8>();
+}
+
+/// Whether every tree is visible from outside the map, with its scenic score
+fn visibility(map: &Grid<u32>) -> Grid<(bool, usize)> {
+    let mut visible_map = Grid::new(map.width(), map.height(), (false, 0));
+
+    for (pos, &height) in map.iter() {
+        let mut visible = false;
+        let mut scenic_score = 1;
+
+        for direction in Direction::CARDINAL {
+            let mut trees = map.ray(pos, direction).map(|(_, &tree)| tree);
+            let seen = trees.position(|tree| tree >= height);
+
+            visible |= seen.is_none();
+            scenic_score *= match seen {
+                Some(idx) => idx + 1,
+                None => map.ray(pos, direction).count(),
+            };
+        }
+        visible_map[pos] = (visible, scenic_score);
+    }
+    visible_map
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_test_input, Solution};
+
+    use crate::{visibility, Day8};
+
+    const TEST_FILE: &str = "inputs/test_input.txt";
+
+    #[test]
+    fn can_read_input() {
+        let input = get_test_input(TEST_FILE, Day8::parse).unwrap();
+
+        assert!(input.height() == 5);
+    }
+
+    #[test]
+    fn can_get_visibility() {
+        let input = get_test_input(TEST_FILE, Day8::parse).unwrap();
+
+        let visibility_map = visibility(&input);
+
+        let visible = visibility_map.iter().filter(|(_, tree)| tree.0).count();
+
+        assert!(visible == 21);
+    }
+
+    #[test]
+    fn can_get_highest_scenic_score() {
+        let input = get_test_input(TEST_FILE, Day8::parse).unwrap();
+
+        let visibility_map = visibility(&input);
+
+        let highest_scenic_score = visibility_map
+            .iter()
+            .filter(|(_, tree)| tree.0)
+            .map(|(_, tree)| tree.1)
+            .max()
+            .unwrap();
+
+        assert!(highest_scenic_score == 8);
+    }
+}
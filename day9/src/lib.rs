@@ -0,0 +1,186 @@
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+use aoc_common::{transform_lines, AocError, Direction, Point, Puzzle, Registry, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const PUZZLE: Puzzle = Puzzle::new(2022, 9);
+    type Input = Vec<Move>;
+
+    fn parse(input: String) -> Result<Self::Input, AocError> {
+        transform_lines(Self::PUZZLE, &input, |s| s.parse::<Move>())
+    }
+
+    fn part1(moves: &Self::Input) -> impl Display {
+        tail_visits(moves, 2).len()
+    }
+
+    fn part2(moves: &Self::Input) -> impl Display {
+        tail_visits(moves, 10).len()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day9>();
+}
+
+/// Pulls a rope of `knots` knots (the head included) through `moves` and
+/// returns every position its tail visited
+fn tail_visits(moves: &[Move], knots: usize) -> HashSet<Point> {
+    let mut rope = vec![Point::ORIGIN; knots.max(1)];
+    let mut visited = HashSet::from([Point::ORIGIN]);
+
+    for mv in moves {
+        for _ in 0..mv.steps {
+            rope[0] += mv.direction;
+            for idx in 1..rope.len() {
+                rope[idx] = follow(rope[idx - 1], rope[idx]);
+            }
+            visited.extend(rope.last());
+        }
+    }
+    visited
+}
+
+/// A knot only moves once it no longer touches the knot ahead of it, and
+/// then takes one (possibly diagonal) step towards it
+fn follow(head: Point, tail: Point) -> Point {
+    if head.chebyshev(tail) > 1 {
+        tail + (head - tail).signum()
+    } else {
+        tail
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Move {
+    direction: Direction,
+    steps: u32,
+}
+
+impl FromStr for Move {
+    type Err = MoveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, steps) = s.split_once(' ').ok_or_else(|| MoveError::with(s))?;
+
+        Ok(Self {
+            direction: direction.parse().map_err(|_| MoveError::with(s))?,
+            steps: steps.parse().map_err(|_| MoveError::with(s))?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MoveError {
+    value: String,
+}
+
+impl MoveError {
+    fn with(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid move: {}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::{get_test_input, Point, Solution};
+
+    use crate::{tail_visits, Day9, Move, MoveError};
+
+    const TEST_FILE: &str = "inputs/test_input.txt";
+    const LARGER_TEST_FILE: &str = "inputs/test_input_2.txt";
+
+    fn moves(s: &str) -> Vec<Move> {
+        Day9::parse(s.to_string()).unwrap()
+    }
+
+    #[test]
+    fn can_read_input() {
+        let input = get_test_input(TEST_FILE, Day9::parse).unwrap();
+
+        assert!(input.len() == 8);
+    }
+
+    #[test]
+    fn fail_on_bad_move() {
+        assert!("X 4".parse::<Move>().err() == Some(MoveError::with("X 4")));
+        assert!("R".parse::<Move>().is_err());
+    }
+
+    #[test]
+    fn can_follow_direction_r() {
+        let result = tail_visits(&moves("R 4"), 2);
+
+        assert!(result.len() == 4);
+    }
+
+    #[test]
+    fn can_follow_direction_l() {
+        let result = tail_visits(&moves("L 4"), 2);
+
+        assert!(result.len() == 4);
+    }
+
+    #[test]
+    fn can_follow_on_diagonal() {
+        let result = tail_visits(&moves("L 2\nU 2"), 2);
+
+        assert!(result.len() == 3);
+    }
+
+    #[test]
+    fn can_follow_up_right() {
+        let result = tail_visits(&moves("U 1\nR 1\nU 1"), 2);
+
+        assert!(result == HashSet::from([Point::ORIGIN, Point::new(1, -1)]));
+    }
+
+    #[test]
+    fn can_follow_down_right() {
+        let result = tail_visits(&moves("D 1\nR 1\nD 1"), 2);
+
+        assert!(result == HashSet::from([Point::ORIGIN, Point::new(1, 1)]));
+    }
+
+    #[test]
+    fn can_follow_up_left() {
+        let result = tail_visits(&moves("U 1\nL 1\nU 1"), 2);
+
+        assert!(result == HashSet::from([Point::ORIGIN, Point::new(-1, -1)]));
+    }
+
+    #[test]
+    fn can_follow_down_left() {
+        let result = tail_visits(&moves("D 1\nL 1\nD 1"), 2);
+
+        assert!(result == HashSet::from([Point::ORIGIN, Point::new(-1, 1)]));
+    }
+
+    #[test]
+    fn can_do_moves() {
+        let moves = get_test_input(TEST_FILE, Day9::parse).unwrap();
+        let result = tail_visits(&moves, 2);
+
+        assert!(result.len() == 13);
+    }
+
+    #[test]
+    fn can_move_ten_knots() {
+        let moves = get_test_input(LARGER_TEST_FILE, Day9::parse).unwrap();
+        let result = tail_visits(&moves, 10);
+
+        assert!(result.len() == 36);
+    }
+}